@@ -1,10 +1,15 @@
+use std::ptr::NonNull;
 use std::sync::Barrier;
 
-use hzrd::domains::LocalDomain;
+use hzrd::core::{Domain, RetiredPtr};
+use hzrd::domains::{
+    Config, GenerationDomain, GlobalDomain, LocalDomain, ReclaimStrategy, SharedDomain,
+};
+use hzrd::seqlock::SeqCell;
 use hzrd::HzrdCell;
 
-fn back_and_forth(n: usize) {
-    let cell = HzrdCell::new(None);
+fn back_and_forth(n: usize, domain: impl Domain + Send + Sync) {
+    let cell = HzrdCell::new_in(None, domain);
     let barrier = Barrier::new(2);
 
     std::thread::scope(|s| {
@@ -30,7 +35,7 @@ fn back_and_forth(n: usize) {
     });
 }
 
-fn local_writer(n: usize) {
+fn local_writer(n: usize, peek: bool) {
     let cell = HzrdCell::new_in(0, LocalDomain::new());
     let barrier = Barrier::new(2);
 
@@ -40,7 +45,10 @@ fn local_writer(n: usize) {
         s.spawn(move || {
             barrier.wait();
             for _ in 0..n {
-                let _ = reader.get();
+                let _ = match peek {
+                    true => *reader.peek(),
+                    false => reader.get(),
+                };
             }
         });
 
@@ -51,6 +59,50 @@ fn local_writer(n: usize) {
     });
 }
 
+fn set_one_by_one(n: usize) {
+    let cell = HzrdCell::new_in(0, LocalDomain::new());
+    for i in 1..n {
+        cell.set(i);
+    }
+}
+
+fn set_many(n: usize) {
+    let cell = HzrdCell::new_in(0, LocalDomain::new());
+    cell.set_many(1..n);
+}
+
+fn shared_domain_with_retired(n: usize) -> SharedDomain {
+    // Nothing is ever reclaimed, so every call to `reclaim` pushes all the values back
+    let domain = SharedDomain::with_config(Config::default().bulk_size(usize::MAX));
+    for i in 0..n {
+        let ptr = NonNull::from(Box::leak(Box::new(i)));
+        domain.just_retire(unsafe { RetiredPtr::new(ptr) });
+    }
+    domain
+}
+
+fn shared_domain_with_protected(n: usize, strategy: ReclaimStrategy) -> SharedDomain {
+    // Every retired value is protected by its own hazard pointer, so nothing is ever reclaimed.
+    // The values are freed when the domain is dropped.
+    let domain = SharedDomain::with_config(Config::default().reclaim_strategy(strategy));
+    for i in 0..n {
+        let ptr = NonNull::from(Box::leak(Box::new(i)));
+        unsafe { domain.hzrd_ptr().protect_nonnull(ptr) };
+        domain.just_retire(unsafe { RetiredPtr::new(ptr) });
+    }
+    domain
+}
+
+fn hzrd_cell_get(n: u64) -> u64 {
+    let cell = HzrdCell::new_in(n, LocalDomain::new());
+    (0..n).map(|_| cell.get()).sum()
+}
+
+fn seq_cell_get(n: u64) -> u64 {
+    let cell = SeqCell::new(n);
+    (0..n).map(|_| cell.get()).sum()
+}
+
 // -------------------------------------
 
 use std::hint::black_box;
@@ -59,11 +111,51 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 pub fn hzrd_cell(c: &mut Criterion) {
     c.bench_function("back-and-forth", |b| {
-        b.iter(|| back_and_forth(black_box(1_000)))
+        b.iter(|| back_and_forth(black_box(1_000), GlobalDomain))
+    });
+
+    c.bench_function("back-and-forth-shared", |b| {
+        b.iter(|| back_and_forth(black_box(1_000), SharedDomain::new()))
+    });
+
+    c.bench_function("back-and-forth-generation", |b| {
+        b.iter(|| back_and_forth(black_box(1_000), GenerationDomain::new()))
     });
 
     c.bench_function("local-writer", |b| {
-        b.iter(|| local_writer(black_box(1_000)))
+        b.iter(|| local_writer(black_box(1_000), false))
+    });
+
+    c.bench_function("local-writer-peek", |b| {
+        b.iter(|| local_writer(black_box(1_000), true))
+    });
+
+    c.bench_function("set-one-by-one", |b| {
+        b.iter(|| set_one_by_one(black_box(1_000)))
+    });
+
+    c.bench_function("set-many", |b| b.iter(|| set_many(black_box(1_000))));
+
+    let domain = shared_domain_with_retired(10_000);
+    c.bench_function("reclaim-10k-retained", |b| b.iter(|| domain.reclaim()));
+    domain.reclaim_all();
+
+    for strategy in [
+        ReclaimStrategy::Linear,
+        ReclaimStrategy::Sorted,
+        ReclaimStrategy::Hashed,
+    ] {
+        let domain = shared_domain_with_protected(1_000, strategy);
+        let name = format!("reclaim-1k-protected-{strategy:?}").to_lowercase();
+        c.bench_function(&name, |b| b.iter(|| domain.reclaim()));
+    }
+
+    c.bench_function("hzrd-cell-get", |b| {
+        b.iter(|| hzrd_cell_get(black_box(1_000)))
+    });
+
+    c.bench_function("seq-cell-get", |b| {
+        b.iter(|| seq_cell_get(black_box(1_000)))
     });
 }
 
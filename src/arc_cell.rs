@@ -0,0 +1,220 @@
+/*!
+A cloneable handle to a [`HzrdCell`]
+
+This module provides the [`ArcCell`], which holds a [`HzrdCell`] in an [`Arc`]. Cloning an [`ArcCell`] does not clone the value, instead both clones refer to the same cell: A value set through one clone can be read through all the others.
+
+```
+use hzrd::arc_cell::ArcCell;
+
+let cell = ArcCell::new(0);
+let clone = cell.clone();
+
+cell.set(1);
+assert_eq!(clone.get(), 1);
+```
+
+# Reclamation
+The cell, and thereby the value it holds, is dropped when the last clone is dropped. Values which have been swapped out are retired to the domain of the cell as usual, and are reclaimed on later writes (or explicit calls to [`reclaim`](HzrdCell::reclaim)) through any of the clones. Dropping the last clone does not reclaim the retired values however. They are only freed along with the domain, if the cell owns it, such as a [`SharedDomain`](crate::domains::SharedDomain) passed by value. Cells in the [`GlobalDomain`], or in a domain shared through a reference or an [`Arc`], leave their retired values to be reclaimed by the next reclamation of the domain.
+*/
+
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use crate::core::Domain;
+use crate::domains::GlobalDomain;
+use crate::{HzrdCell, OwnedReader};
+
+/**
+Holds a [`HzrdCell`] which is shared between all clones
+
+The [`ArcCell`] dereferences to the [`HzrdCell`] it holds, so the full API of the cell is available. See the [module-level documentation](crate::arc_cell) for more details.
+
+# Example
+```
+use std::sync::Arc;
+
+use hzrd::arc_cell::ArcCell;
+use hzrd::domains::SharedDomain;
+
+let cell = ArcCell::new_in(String::from("Hello"), Arc::new(SharedDomain::new()));
+
+let clone = cell.clone();
+std::thread::spawn(move || clone.set(String::from("World")))
+    .join()
+    .unwrap();
+
+assert_eq!(*cell.read(), "World");
+```
+*/
+pub struct ArcCell<T, D = GlobalDomain> {
+    cell: Arc<HzrdCell<T, D>>,
+}
+
+impl<T: 'static> ArcCell<T> {
+    /**
+    Construct a new [`ArcCell`] with the given value in the default domain
+
+    # Example
+    ```
+    # use hzrd::arc_cell::ArcCell;
+    let cell = ArcCell::new(0);
+    # assert_eq!(cell.get(), 0);
+    ```
+    */
+    pub fn new(value: T) -> Self {
+        Self::new_in(value, GlobalDomain)
+    }
+}
+
+impl<T: 'static, D> ArcCell<T, D> {
+    /**
+    Construct a new [`ArcCell`] with the given value in the given domain
+
+    See [`HzrdCell::new_in`] for more details.
+
+    # Example
+    ```
+    # use hzrd::arc_cell::ArcCell;
+    # use hzrd::domains::SharedDomain;
+    let cell = ArcCell::new_in(0, SharedDomain::new());
+    # assert_eq!(cell.get(), 0);
+    ```
+    */
+    pub fn new_in(value: T, domain: D) -> Self {
+        Self::from(HzrdCell::new_in(value, domain))
+    }
+}
+
+impl<T, D> ArcCell<T, D> {
+    /**
+    Check if two [`ArcCell`]s refer to the same cell
+
+    # Example
+    ```
+    # use hzrd::arc_cell::ArcCell;
+    let cell = ArcCell::new(0);
+    assert!(ArcCell::ptr_eq(&cell, &cell.clone()));
+    assert!(!ArcCell::ptr_eq(&cell, &ArcCell::new(0)));
+    ```
+    */
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.cell, &other.cell)
+    }
+}
+
+impl<T: 'static, D: Domain> ArcCell<T, D> {
+    /**
+    Construct a reader to the cell which does not borrow the cell
+
+    See [`HzrdCell::owned_reader`] for more details.
+
+    # Example
+    ```
+    # use hzrd::arc_cell::ArcCell;
+    let cell = ArcCell::new(false);
+    let mut reader = cell.owned_reader();
+    # assert_eq!(reader.get(), false);
+    ```
+    */
+    pub fn owned_reader(&self) -> OwnedReader<T, D> {
+        HzrdCell::owned_reader(&self.cell)
+    }
+}
+
+impl<T, D> Clone for ArcCell<T, D> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: Arc::clone(&self.cell),
+        }
+    }
+}
+
+impl<T, D> Deref for ArcCell<T, D> {
+    type Target = HzrdCell<T, D>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cell
+    }
+}
+
+impl<T, D> From<HzrdCell<T, D>> for ArcCell<T, D> {
+    fn from(cell: HzrdCell<T, D>) -> Self {
+        Self {
+            cell: Arc::new(cell),
+        }
+    }
+}
+
+impl<T, D> From<Arc<HzrdCell<T, D>>> for ArcCell<T, D> {
+    fn from(cell: Arc<HzrdCell<T, D>>) -> Self {
+        Self { cell }
+    }
+}
+
+impl<T, D> core::fmt::Debug for ArcCell<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArcCell").finish_non_exhaustive()
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering::*};
+    use std::sync::Arc;
+
+    use super::ArcCell;
+    use crate::core::Domain;
+    use crate::domains::SharedDomain;
+
+    /// Increments the counter when dropped
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+
+    #[test]
+    fn drop_last_clone() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let domain = Arc::new(SharedDomain::new());
+
+        let cell = ArcCell::new_in(DropCounter(Arc::clone(&drops)), Arc::clone(&domain));
+        let clones: Vec<_> = (0..4).map(|_| cell.clone()).collect();
+
+        // Writing through a clone retires the old value to the shared domain
+        let handle = cell.read();
+        clones[0].set(DropCounter(Arc::clone(&drops)));
+        assert_eq!(domain.retired_count(), 1);
+        drop(handle);
+
+        clones[1].reclaim();
+        assert_eq!(drops.load(SeqCst), 1);
+
+        // The current value is only dropped along with the last clone
+        drop(cell);
+        drop(clones);
+        assert_eq!(drops.load(SeqCst), 2);
+    }
+
+    #[test]
+    fn drop_last_clone_shared_domain() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let domain = Arc::new(SharedDomain::new());
+
+        let cell = ArcCell::new_in(DropCounter(Arc::clone(&drops)), Arc::clone(&domain));
+        let handle = cell.read();
+        cell.set(DropCounter(Arc::clone(&drops)));
+        drop(handle);
+
+        // The retired value is left in the domain, which outlives the cell
+        drop(cell);
+        assert_eq!(drops.load(SeqCst), 1);
+        assert_eq!(domain.retired_count(), 1);
+        assert_eq!(domain.reclaim(), 1);
+        assert_eq!(drops.load(SeqCst), 2);
+    }
+}
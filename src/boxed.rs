@@ -0,0 +1,203 @@
+/*!
+Support for unsized values, such as trait objects and slices
+
+A [`HzrdCell`] stores its value behind a thin atomic pointer, which means the value must be [`Sized`]. This module provides the [`HzrdBox`], which can hold unsized values such as `dyn Trait` or `[u8]`. The unsized value is stored in a [`Box`], which in turn is held by a [`HzrdCell`]. The cell only ever swaps thin pointers to the boxes, and reads are projected through the box.
+
+```
+use std::fmt::Display;
+
+use hzrd::boxed::HzrdBox;
+
+let cell: HzrdBox<dyn Display + Send + Sync> = HzrdBox::new(Box::new(42));
+assert_eq!(cell.read().to_string(), "42");
+
+cell.set(Box::new("Hello"));
+assert_eq!(cell.read().to_string(), "Hello");
+```
+
+# Limitations
+Unsized values can not be copied or cloned out of the cell, and so there is no equivalent of [`get`](HzrdCell::get), [`read_boxed`](HzrdCell::read_boxed), [`replace`](HzrdCell::replace) or [`take`](HzrdCell::take). Values can also not be compared for [`compare_exchange`](HzrdCell::compare_exchange). Reads return a [`MappedReadHandle`], rather than a [`ReadHandle`].
+
+Note also that every value requires two allocations, one for the value and one for the box holding it.
+*/
+
+use alloc::boxed::Box;
+
+use crate::core::{Domain, MappedReadHandle, ReadHandle};
+use crate::domains::GlobalDomain;
+use crate::HzrdCell;
+
+/**
+Holds a value which may be unsized, such as a trait object or a slice
+
+See the [module-level documentation](crate::boxed) for more details.
+
+# Example
+```
+use hzrd::boxed::HzrdBox;
+
+let cell: HzrdBox<[u8]> = HzrdBox::new(Box::new([1, 2, 3]));
+assert_eq!(cell.read().len(), 3);
+
+cell.set(vec![4, 5].into_boxed_slice());
+assert_eq!(&*cell.read(), [4, 5]);
+```
+*/
+pub struct HzrdBox<T: ?Sized, D = GlobalDomain> {
+    cell: HzrdCell<Box<T>, D>,
+}
+
+impl<T: ?Sized + 'static> HzrdBox<T> {
+    /**
+    Construct a new [`HzrdBox`] with the given value in the default domain
+
+    # Example
+    ```
+    # use hzrd::boxed::HzrdBox;
+    let cell: HzrdBox<str> = HzrdBox::new(Box::from("Hello"));
+    # assert_eq!(&*cell.read(), "Hello");
+    ```
+    */
+    pub fn new(boxed: Box<T>) -> Self {
+        Self::new_in(boxed, GlobalDomain)
+    }
+}
+
+impl<T: ?Sized + 'static, D> HzrdBox<T, D> {
+    /**
+    Construct a new [`HzrdBox`] with the given value in the given domain
+
+    See [`HzrdCell::new_in`] for more details.
+
+    # Example
+    ```
+    # use hzrd::boxed::HzrdBox;
+    # use hzrd::domains::SharedDomain;
+    let cell: HzrdBox<str, _> = HzrdBox::new_in(Box::from("Hello"), SharedDomain::new());
+    # assert_eq!(&*cell.read(), "Hello");
+    ```
+    */
+    pub fn new_in(boxed: Box<T>, domain: D) -> Self {
+        Self {
+            cell: HzrdCell::new_in(boxed, domain),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static, D: Domain> HzrdBox<T, D> {
+    /**
+    Get a handle holding a reference to the current value
+
+    See [`HzrdCell::read`] for more details.
+
+    # Example
+    ```
+    # use hzrd::boxed::HzrdBox;
+    let cell: HzrdBox<[i32]> = HzrdBox::new(Box::new([1, 2, 3]));
+    let handle = cell.read();
+    assert_eq!(handle.iter().sum::<i32>(), 6);
+    ```
+    */
+    pub fn read(&self) -> MappedReadHandle<'_, Box<T>, T> {
+        ReadHandle::map(self.cell.read(), |boxed| &**boxed)
+    }
+
+    /**
+    Set the value of the cell
+
+    See [`HzrdCell::set`] for more details.
+
+    # Example
+    ```
+    # use hzrd::boxed::HzrdBox;
+    let cell: HzrdBox<str> = HzrdBox::new(Box::from("Hello"));
+    cell.set(Box::from("World"));
+    # assert_eq!(&*cell.read(), "World");
+    ```
+    */
+    pub fn set(&self, boxed: Box<T>) {
+        self.cell.set(boxed);
+    }
+
+    /// Set the value of the cell without attempting to reclaim memory
+    pub fn just_set(&self, boxed: Box<T>) {
+        self.cell.just_set(boxed);
+    }
+
+    /**
+    Reclaim available memory, if possible
+
+    See [`HzrdCell::reclaim`] for more details.
+    */
+    pub fn reclaim(&self) {
+        self.cell.reclaim();
+    }
+}
+
+impl<T: ?Sized + 'static> From<Box<T>> for HzrdBox<T> {
+    fn from(boxed: Box<T>) -> Self {
+        Self::new(boxed)
+    }
+}
+
+impl<T: ?Sized, D> core::fmt::Debug for HzrdBox<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HzrdBox").finish_non_exhaustive()
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering::*};
+    use std::sync::Arc;
+
+    use super::HzrdBox;
+    use crate::domains::SharedDomain;
+
+    trait Shape {
+        fn area(&self) -> f64;
+    }
+
+    struct Square(f64);
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+
+    struct Circle(f64, Arc<AtomicUsize>);
+
+    impl Shape for Circle {
+        fn area(&self) -> f64 {
+            3.0 * self.0 * self.0
+        }
+    }
+
+    impl Drop for Circle {
+        fn drop(&mut self) {
+            self.1.fetch_add(1, SeqCst);
+        }
+    }
+
+    #[test]
+    fn trait_object() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let circle = Circle(1.0, Arc::clone(&drops));
+        let cell: HzrdBox<dyn Shape, _> = HzrdBox::new_in(Box::new(circle), SharedDomain::new());
+        assert_eq!(cell.read().area(), 3.0);
+
+        let handle = cell.read();
+        cell.set(Box::new(Square(2.0)));
+        assert_eq!(cell.read().area(), 4.0);
+
+        // The old value is only dropped once it's no longer read
+        assert_eq!(handle.area(), 3.0);
+        assert_eq!(drops.load(SeqCst), 0);
+        drop(handle);
+        cell.reclaim();
+        assert_eq!(drops.load(SeqCst), 1);
+    }
+}
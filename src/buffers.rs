@@ -0,0 +1,289 @@
+/*!
+Double-buffering, based on [`HzrdCell`]
+
+This module provides the [`DoubleBuffer`], which holds a front buffer and a back buffer. Readers read the front buffer through hazard pointers, while a producer mutates the back buffer in place. Once the back buffer is ready it's published, which makes it the new front buffer. This is a common pattern for e.g. rendering or telemetry snapshots, where readers should only ever see complete states.
+
+```
+use hzrd::buffers::DoubleBuffer;
+
+let buffer = DoubleBuffer::new(vec![0; 4]);
+
+buffer.back()[0] = 1;
+assert_eq!(*buffer.read(), [0, 0, 0, 0]);
+
+buffer.publish();
+assert_eq!(*buffer.read(), [1, 0, 0, 0]);
+```
+*/
+
+use std::borrow::Borrow;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::core::{Domain, ReadHandle};
+use crate::domains::{GlobalDomain, RecyclingDomain};
+use crate::HzrdCell;
+
+/**
+Holds a front buffer for readers, and a back buffer for a producer
+
+The front buffer is held by a [`HzrdCell`], and is read with [`read`](DoubleBuffer::read). The back buffer is protected by a [`Mutex`], and is accessed with [`back`](DoubleBuffer::back). Calling [`publish`](DoubleBuffer::publish) swaps the back buffer into the front, and the previous front buffer is retired. The back buffer then starts out as a clone of what was just published, such that the producer can keep making incremental changes.
+
+The clone is a new allocation on every publish, as the previous front buffer may still be read. If that is too costly, put the buffer in a [`RecyclingDomain`] and use [`publish_recycled`](DoubleBuffer::publish_recycled), which reuses the retired front buffers once they are reclaimed.
+
+# Example
+```
+use hzrd::buffers::DoubleBuffer;
+
+let buffer = DoubleBuffer::new(String::new());
+
+std::thread::scope(|s| {
+    s.spawn(|| {
+        for word in ["Hello", " ", "World"] {
+            buffer.back().push_str(word);
+            buffer.publish();
+        }
+    });
+
+    s.spawn(|| {
+        // Readers never see a partially written state
+        let string = buffer.read();
+        assert!(["", "Hello", "Hello ", "Hello World"].contains(&string.as_str()));
+    });
+});
+```
+*/
+pub struct DoubleBuffer<T, D = GlobalDomain> {
+    front: HzrdCell<T, D>,
+    back: Mutex<Back<T>>,
+}
+
+/// The back buffer, along with reclaimed front buffers waiting to be reused
+struct Back<T> {
+    buffer: Box<T>,
+    recycled: Vec<Box<T>>,
+}
+
+impl<T: Clone + 'static> DoubleBuffer<T> {
+    /**
+    Construct a new [`DoubleBuffer`] in the default domain, with both buffers holding the given value
+
+    # Example
+    ```
+    # use hzrd::buffers::DoubleBuffer;
+    let buffer = DoubleBuffer::new(0);
+    # assert_eq!(*buffer.read(), 0);
+    ```
+    */
+    pub fn new(value: T) -> Self {
+        Self::new_in(value, GlobalDomain)
+    }
+}
+
+impl<T: Clone + 'static, D> DoubleBuffer<T, D> {
+    /**
+    Construct a new [`DoubleBuffer`] in the given domain, with both buffers holding the given value
+
+    # Example
+    ```
+    # use hzrd::buffers::DoubleBuffer;
+    # use hzrd::domains::SharedDomain;
+    let buffer = DoubleBuffer::new_in(0, SharedDomain::new());
+    # assert_eq!(*buffer.read(), 0);
+    ```
+    */
+    pub fn new_in(value: T, domain: D) -> Self {
+        Self {
+            back: Mutex::new(Back {
+                buffer: Box::new(value.clone()),
+                recycled: Vec::new(),
+            }),
+            front: HzrdCell::new_in(value, domain),
+        }
+    }
+}
+
+impl<T: 'static, D: Domain> DoubleBuffer<T, D> {
+    /**
+    Get a handle holding a reference to the front buffer
+
+    See [`HzrdCell::read`] for more details.
+
+    # Example
+    ```
+    # use hzrd::buffers::DoubleBuffer;
+    let buffer = DoubleBuffer::new([1, 2, 3]);
+    assert_eq!(*buffer.read(), [1, 2, 3]);
+    ```
+    */
+    pub fn read(&self) -> ReadHandle<'_, T> {
+        self.front.read()
+    }
+
+    /**
+    Get exclusive access to the back buffer
+
+    Only one thread can access the back buffer at a time, other threads will block until the returned guard is dropped. This includes calls to [`publish`](DoubleBuffer::publish).
+
+    # Example
+    ```
+    # use hzrd::buffers::DoubleBuffer;
+    let buffer = DoubleBuffer::new(0);
+    *buffer.back() += 1;
+    # buffer.publish();
+    # assert_eq!(*buffer.read(), 1);
+    ```
+    */
+    pub fn back(&self) -> BackBuffer<'_, T> {
+        BackBuffer {
+            guard: self.back.lock().unwrap_or_else(|e| e.into_inner()),
+        }
+    }
+
+    /**
+    Publish the back buffer, making it the new front buffer
+
+    The back buffer is moved into the front without copying it, and the previous front buffer is retired. The new back buffer is a clone of the published value, in a new allocation. See [`publish_recycled`](DoubleBuffer::publish_recycled) for reusing the allocations instead.
+
+    # Example
+    ```
+    # use hzrd::buffers::DoubleBuffer;
+    let buffer = DoubleBuffer::new(String::from("Hello"));
+    buffer.back().push_str(" World");
+    buffer.publish();
+    assert_eq!(*buffer.read(), "Hello World");
+    ```
+    */
+    pub fn publish(&self)
+    where
+        T: Clone,
+    {
+        let mut back = self.back.lock().unwrap_or_else(|e| e.into_inner());
+        let new_back = Box::new(T::clone(&back.buffer));
+        let published = std::mem::replace(&mut back.buffer, new_back);
+
+        self.front.set_boxed(published);
+    }
+}
+
+impl<T: Clone + 'static, D: Domain + Borrow<RecyclingDomain<T>>> DoubleBuffer<T, D> {
+    /**
+    Publish the back buffer, reusing a reclaimed front buffer as the new back buffer
+
+    This is the same as [`publish`](DoubleBuffer::publish), except that the published value is cloned into the allocation of a previous front buffer (using [`Clone::clone_from`]), if one has been reclaimed. The retired front buffers are collected with [`reclaim_into_pool`](RecyclingDomain::reclaim_into_pool), and so a new allocation is only made if none of them could be reclaimed, such as while readers are holding on to them.
+
+    # Example
+    ```
+    use hzrd::buffers::DoubleBuffer;
+    use hzrd::domains::RecyclingDomain;
+
+    let buffer = DoubleBuffer::new_in(vec![0; 1024], RecyclingDomain::new());
+    for i in 0..10 {
+        buffer.back()[0] = i;
+        buffer.publish_recycled();
+        assert_eq!(buffer.read()[0], i);
+    }
+    ```
+    */
+    pub fn publish_recycled(&self) {
+        let mut back = self.back.lock().unwrap_or_else(|e| e.into_inner());
+        if back.recycled.is_empty() {
+            let Back { recycled, .. } = &mut *back;
+            self.front.domain.borrow().reclaim_into_pool(recycled);
+        }
+
+        let new_back = match back.recycled.pop() {
+            Some(mut recycled) => {
+                recycled.clone_from(&back.buffer);
+                recycled
+            }
+            None => Box::new(T::clone(&back.buffer)),
+        };
+        let published = std::mem::replace(&mut back.buffer, new_back);
+
+        // The previous front buffer is reclaimed into the pool on a later publish
+        self.front.just_set_boxed(published);
+    }
+}
+
+impl<T, D> std::fmt::Debug for DoubleBuffer<T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DoubleBuffer").finish_non_exhaustive()
+    }
+}
+
+/// Exclusive access to the back buffer of a [`DoubleBuffer`], see [`DoubleBuffer::back`]
+pub struct BackBuffer<'buffer, T> {
+    guard: MutexGuard<'buffer, Back<T>>,
+}
+
+impl<T> Deref for BackBuffer<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.guard.buffer
+    }
+}
+
+impl<T> DerefMut for BackBuffer<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard.buffer
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::DoubleBuffer;
+    use crate::core::Domain;
+    use crate::domains::{RecyclingDomain, SharedDomain};
+
+    #[test]
+    fn publish() {
+        let domain = SharedDomain::new();
+        let buffer = DoubleBuffer::new_in(vec![1, 2, 3], &domain);
+
+        buffer.back().push(4);
+        let handle = buffer.read();
+        assert_eq!(*handle, [1, 2, 3]);
+
+        buffer.publish();
+        assert_eq!(*buffer.read(), [1, 2, 3, 4]);
+
+        // The back buffer continues from the published value
+        assert_eq!(*buffer.back(), [1, 2, 3, 4]);
+
+        // The old front buffer is kept alive by the handle
+        assert_eq!(domain.retired_count(), 1);
+        assert_eq!(*handle, [1, 2, 3]);
+        drop(handle);
+
+        buffer.publish();
+        assert_eq!(domain.retired_count(), 0);
+    }
+
+    #[test]
+    fn publish_recycled() {
+        let domain = RecyclingDomain::new();
+        let buffer = DoubleBuffer::new_in(vec![0; 16], &domain);
+        let front = |buffer: &DoubleBuffer<_, _>| &*buffer.read() as *const Vec<i32>;
+
+        // Nothing is reclaimed while the old front buffer is read
+        let handle = buffer.read();
+        let first = front(&buffer);
+        buffer.back()[0] = 1;
+        buffer.publish_recycled();
+        buffer.back()[0] = 2;
+        buffer.publish_recycled();
+        assert_eq!(domain.retired_count(), 2);
+        assert_eq!(*handle, [0; 16]);
+        drop(handle);
+
+        // ...but once it's released, its allocation is reused for the back buffer
+        buffer.back()[0] = 3;
+        buffer.publish_recycled();
+        buffer.publish_recycled();
+        assert_eq!(front(&buffer), first);
+        assert_eq!(buffer.read()[0], 3);
+    }
+}
@@ -12,12 +12,23 @@ These are used in the [`Domain`] interface, and can be considered the fundamenta
 
 // -------------------------------------
 
-use std::ops::Deref;
-use std::ptr::{addr_of, NonNull};
-use std::rc::Rc;
-use std::sync::atomic::Ordering::*;
-use std::sync::atomic::{AtomicPtr, AtomicUsize};
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr::{addr_of, NonNull};
+use core::sync::atomic::Ordering::*;
+use core::time::Duration;
+#[cfg(all(debug_assertions, feature = "std"))]
+use std::{sync::OnceLock, time::Instant};
+
+#[cfg(debug_assertions)]
+use crate::sync::AtomicU64;
+use crate::sync::{fence, AtomicPtr, AtomicUsize};
 
 // ------------------------------
 
@@ -28,6 +39,19 @@ pub enum Action {
     Reset,
     /// Release hazard pointer
     Release,
+    /// Keep protecting the value, the hazard pointer is left as is
+    Keep,
+}
+
+impl Action {
+    /// SAFETY: The caller must be the current "owner" of the hazard pointer
+    unsafe fn perform(self, hzrd_ptr: &HzrdPtr) {
+        match self {
+            Action::Reset => unsafe { hzrd_ptr.reset() },
+            Action::Release => unsafe { hzrd_ptr.release() },
+            Action::Keep => {}
+        }
+    }
 }
 
 /**
@@ -36,6 +60,8 @@ Holds a reference to a read value. The value is kept alive by a hazard pointer.
 Note that the reference held by the handle is to the value as it was when it was read.
 If the cell is written to during the lifetime of the handle this will not be reflected in its value.
 
+Unlike a [`MutexGuard`](std::sync::MutexGuard) there is no poisoning. If a thread panics while holding a handle the hazard pointer is released as the handle is dropped during unwinding, and the value can be reclaimed as usual. With `panic = "abort"` there is no unwinding, but then the whole process is terminated anyway.
+
 # Example
 ```
 # use hzrd::HzrdCell;
@@ -102,28 +128,254 @@ impl<'hzrd, T> ReadHandle<'hzrd, T> {
         hzrd_ptr: &'hzrd HzrdPtr,
         action: Action,
     ) -> Self {
-        let mut ptr = value.load(SeqCst);
-        loop {
-            // SAFETY: ptr is not null
-            unsafe { hzrd_ptr.protect(ptr) };
+        // SAFETY: Same requirements as this function
+        let value = unsafe { hzrd_ptr.load_protected(value) };
 
-            // We now need to keep updating it until it is in a consistent state
-            let new_ptr = value.load(SeqCst);
-            if ptr == new_ptr {
-                break;
-            } else {
-                ptr = new_ptr;
+        Self {
+            value,
+            hzrd_ptr,
+            action,
+        }
+    }
+
+    /**
+    Read value of an atomic pointer belonging to the given domain, and protect the reference using a hazard pointer.
+
+    This is the same as [`read_unchecked`](`ReadHandle::read_unchecked`), except that debug builds will check that the hazard pointer was handed out by the given domain. Protecting a value with a hazard pointer from another domain means the value can be freed while it's still in use, as the domain it's retired to will not see the hazard pointer.
+
+    # Panics
+    In debug builds, this panics if the hazard pointer belongs to a different domain. The check is skipped if either the domain or the hazard pointer has no id (see [`Domain::domain_id`]).
+
+    # Safety
+    Same requirements as [`read_unchecked`](`ReadHandle::read_unchecked`)
+
+    # Example
+    ```
+    use std::sync::atomic::{AtomicPtr, Ordering::*};
+
+    use hzrd::core::{Action, Domain, ReadHandle};
+    use hzrd::domains::SharedDomain;
+
+    let value = AtomicPtr::new(Box::into_raw(Box::new(0)));
+    let domain = SharedDomain::new();
+
+    let hzrd_ptr = domain.hzrd_ptr();
+    let handle = unsafe { ReadHandle::read_unchecked_in(&value, hzrd_ptr, Action::Release, &domain) };
+    assert_eq!(*handle, 0);
+    # drop(handle);
+    # let _ = unsafe { Box::from_raw(value.load(SeqCst)) };
+    ```
+    */
+    pub unsafe fn read_unchecked_in<D: Domain + ?Sized>(
+        value: &'hzrd AtomicPtr<T>,
+        hzrd_ptr: &'hzrd HzrdPtr,
+        action: Action,
+        domain: &D,
+    ) -> Self {
+        hzrd_ptr.debug_assert_domain(domain);
+
+        // SAFETY: Same requirements as this function
+        unsafe { Self::read_unchecked(value, hzrd_ptr, action) }
+    }
+
+    /**
+    Read value of an atomic pointer, reusing the protection of the hazard pointer if it already protects the current value
+
+    If the hazard pointer already holds the current value of the atomic pointer, the value was verified when it was protected, and the hazard pointer is not written to again. Otherwise this is the same as [`read_unchecked`](`ReadHandle::read_unchecked`). The hazard pointer is kept as is when the handle is dropped.
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The hazard pointer must only have been set by a previous read of the same atomic pointer
+    - The value of the atomic pointer must be protected by the given hazard pointer
+    */
+    pub(crate) unsafe fn peek_unchecked(
+        value: &'hzrd AtomicPtr<T>,
+        hzrd_ptr: &'hzrd HzrdPtr,
+    ) -> Self {
+        let ptr = value.load(SeqCst);
+        if hzrd_ptr.get() == ptr as usize {
+            // SAFETY: The value has been protected since it was verified, and it can not be reclaimed
+            let value = unsafe { &*ptr };
+            return Self {
+                value,
+                hzrd_ptr,
+                action: Action::Keep,
+            };
+        }
+
+        // SAFETY: Same requirements as this function
+        unsafe { Self::read_unchecked(value, hzrd_ptr, Action::Keep) }
+    }
+
+    /**
+    Try to read the value of an atomic pointer, giving up after the given number of attempts
+
+    Each attempt consists of protecting the loaded pointer, and then verifying that the atomic pointer is unchanged. If no attempt succeeds the action is performed on the hazard pointer immediately, and `None` is returned.
+
+    # Safety
+    Same requirements as [`read_unchecked`](`ReadHandle::read_unchecked`)
+    */
+    pub(crate) unsafe fn try_read_unchecked(
+        value: &'hzrd AtomicPtr<T>,
+        hzrd_ptr: &'hzrd HzrdPtr,
+        action: Action,
+        attempts: usize,
+    ) -> Option<Self> {
+        // SAFETY: The caller guarantees that the atomic pointer is never null
+        let mut ptr = unsafe { NonNull::new_unchecked(value.load(SeqCst)) };
+        for _ in 0..attempts {
+            // SAFETY: Same requirements as this function
+            match unsafe { Self::try_protect_unchecked(value, hzrd_ptr, ptr, action) } {
+                Ok(handle) => return Some(handle),
+                Err(new_ptr) => ptr = new_ptr,
             }
         }
-        std::sync::atomic::fence(SeqCst);
+
+        // SAFETY: We are still the owner of the hazard pointer
+        unsafe { action.perform(hzrd_ptr) };
+        None
+    }
+
+    /**
+    Protect a pointer loaded from an atomic pointer, and verify that the atomic pointer still holds it
+
+    If the atomic pointer has changed in the meantime its new value is returned instead. The hazard pointer is then left protecting the old pointer, and no action is performed on it.
+
+    # Safety
+    Same requirements as [`read_unchecked`](`ReadHandle::read_unchecked`)
+    */
+    pub(crate) unsafe fn try_protect_unchecked(
+        value: &'hzrd AtomicPtr<T>,
+        hzrd_ptr: &'hzrd HzrdPtr,
+        ptr: NonNull<T>,
+        action: Action,
+    ) -> Result<Self, NonNull<T>> {
+        // SAFETY: We are the owner of the hazard pointer
+        unsafe { hzrd_ptr.protect_nonnull(ptr) };
+
+        // The protection must be visible to writers before the pointer is verified
+        fence(SeqCst);
+
+        // SAFETY: The caller guarantees that the atomic pointer is never null
+        let new_ptr = unsafe { NonNull::new_unchecked(value.load(SeqCst)) };
+        if ptr != new_ptr {
+            return Err(new_ptr);
+        }
 
         // SAFETY: This pointer is now held valid by the hazard pointer
-        let value = unsafe { &*ptr };
+        let value = unsafe { ptr.as_ref() };
 
-        Self {
+        Ok(Self {
             value,
             hzrd_ptr,
             action,
+        })
+    }
+
+    /// Get the pointer to the value protected by this handle
+    pub(crate) fn as_ptr(&self) -> *mut T {
+        self.value as *const T as *mut T
+    }
+
+    /**
+    Clone the read value (requires the type to be [`Clone`])
+
+    This is the same as `T::clone(&handle)`, but spelled out. The handle itself is not [`Clone`], as each handle holds its own hazard pointer, see [`HzrdCell::try_clone_handle`](`crate::HzrdCell::try_clone_handle`) for getting a second handle to the same value.
+
+    This is an associated function, as a method could shadow a method of the value being read.
+
+    # Example
+    ```
+    use hzrd::core::ReadHandle;
+    use hzrd::HzrdCell;
+
+    let cell = HzrdCell::new(String::from("Hello"));
+    let string: String = ReadHandle::clone_value(&cell.read());
+    assert_eq!(string, "Hello");
+    ```
+    */
+    pub fn clone_value(this: &Self) -> T
+    where
+        T: Clone,
+    {
+        T::clone(this.value)
+    }
+
+    /**
+    Make a new handle to a component of the read value, such as a field of a struct
+
+    The hazard pointer keeps protecting the whole value, and the same action is performed on it when the new handle is dropped.
+
+    This is an associated function, as a method could shadow a method of the value being read.
+
+    # Example
+    ```
+    use hzrd::core::ReadHandle;
+    use hzrd::HzrdCell;
+
+    struct Config {
+        name: String,
+        retries: u32,
+    }
+
+    let cell = HzrdCell::new(Config {
+        name: String::from("hzrd"),
+        retries: 3,
+    });
+
+    let name = ReadHandle::map(cell.read(), |config| &config.name);
+    assert_eq!(*name, "hzrd");
+    # assert_eq!(cell.read().retries, 3);
+    ```
+    */
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> MappedReadHandle<'hzrd, T, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        // The closure is called before disarming the handle, in case it panics
+        let value = f(this.value);
+        Self::into_mapped(this, value)
+    }
+
+    /**
+    Try to make a new handle to a component of the read value, such as a variant of an enum
+
+    If the closure returns `None` the original handle is returned, and the value is still protected. Otherwise this works just like [`map`](`ReadHandle::map`).
+
+    # Example
+    ```
+    use hzrd::core::ReadHandle;
+    use hzrd::HzrdCell;
+
+    let cell = HzrdCell::new(Some(String::from("Hello")));
+
+    let string = ReadHandle::try_map(cell.read(), Option::as_ref).ok().unwrap();
+    assert_eq!(*string, "Hello");
+    # drop(string);
+
+    cell.set(None);
+    let handle = ReadHandle::try_map(cell.read(), Option::as_ref).err().unwrap();
+    assert!(handle.is_none());
+    ```
+    */
+    pub fn try_map<U: ?Sized, F>(this: Self, f: F) -> Result<MappedReadHandle<'hzrd, T, U>, Self>
+    where
+        F: FnOnce(&T) -> Option<&U>,
+    {
+        match f(this.value) {
+            Some(value) => Ok(Self::into_mapped(this, value)),
+            None => Err(this),
+        }
+    }
+
+    /// Hand over the hazard pointer to a mapped handle holding the given reference
+    fn into_mapped<U: ?Sized>(this: Self, value: &'hzrd U) -> MappedReadHandle<'hzrd, T, U> {
+        let this = ManuallyDrop::new(this);
+        MappedReadHandle {
+            value,
+            hzrd_ptr: this.hzrd_ptr,
+            action: this.action,
+            _marker: PhantomData,
         }
     }
 }
@@ -138,10 +390,42 @@ impl<T> Deref for ReadHandle<'_, T> {
 impl<T> Drop for ReadHandle<'_, T> {
     fn drop(&mut self) {
         // SAFETY: We are dropping so `value` will never be accessed after this
-        match self.action {
-            Action::Reset => unsafe { self.hzrd_ptr.reset() },
-            Action::Release => unsafe { self.hzrd_ptr.release() },
-        }
+        unsafe { self.action.perform(self.hzrd_ptr) };
+    }
+}
+
+/**
+Holds a reference to a component of a read value, made with [`ReadHandle::map`]
+
+The whole value is kept alive by a hazard pointer, just like for the [`ReadHandle`] it was made from.
+
+# Example
+```
+# use hzrd::core::ReadHandle;
+# use hzrd::HzrdCell;
+let cell = HzrdCell::new((0, String::from("Hello")));
+let handle = ReadHandle::map(cell.read(), |(_, string)| string.as_str());
+assert_eq!(&*handle, "Hello");
+```
+*/
+pub struct MappedReadHandle<'hzrd, T, U: ?Sized> {
+    value: &'hzrd U,
+    hzrd_ptr: &'hzrd HzrdPtr,
+    action: Action,
+    _marker: PhantomData<&'hzrd T>,
+}
+
+impl<T, U: ?Sized> Deref for MappedReadHandle<'_, T, U> {
+    type Target = U;
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T, U: ?Sized> Drop for MappedReadHandle<'_, T, U> {
+    fn drop(&mut self) {
+        // SAFETY: We are dropping so `value` will never be accessed after this
+        unsafe { self.action.perform(self.hzrd_ptr) };
     }
 }
 
@@ -181,119 +465,808 @@ pub unsafe trait Domain {
         self.just_retire(ret_ptr);
         self.reclaim()
     }
-}
 
-// https://stackoverflow.com/questions/63963544/automatically-derive-traits-implementation-for-arc
-macro_rules! deref_impl {
-    ($($sig:tt)+) => {
-        unsafe impl $($sig)+ {
-            fn hzrd_ptr(&self) -> &HzrdPtr {
-                (**self).hzrd_ptr()
-            }
+    /**
+    Retire all the provided retired-pointers, and then reclaim all "reclaimable" memory once
 
-            fn just_retire(&self, ret_ptr: RetiredPtr) {
-                (**self).just_retire(ret_ptr);
-            }
+    Calling [`retire`](`Domain::retire`) for each pointer would scan the hazard pointers once per retired pointer. This only does a single scan at the end.
 
-            fn reclaim(&self) -> usize {
-                (**self).reclaim()
-            }
+    The method must return the number of reclaimed objects
+
+    # Example
+    ```
+    use std::ptr::NonNull;
+
+    use hzrd::core::{Domain, RetiredPtr};
+    use hzrd::domains::SharedDomain;
+
+    let domain = SharedDomain::new();
+    let ret_ptrs = (0..4).map(|i| {
+        let ptr = NonNull::from(Box::leak(Box::new(i)));
+        unsafe { RetiredPtr::new(ptr) }
+    });
+
+    assert_eq!(domain.batch_retire(ret_ptrs), 4);
+    ```
+    */
+    fn batch_retire<I>(&self, ret_ptrs: I) -> usize
+    where
+        I: IntoIterator<Item = RetiredPtr>,
+        Self: Sized,
+    {
+        for ret_ptr in ret_ptrs {
+            self.just_retire(ret_ptr);
         }
-    };
-}
+        self.reclaim()
+    }
 
-deref_impl!(<D: Domain> Domain for &D);
-deref_impl!(<D: Domain> Domain for Rc<D>);
-deref_impl!(<D: Domain> Domain for Arc<D>);
+    /**
+    Reclaim all "reclaimable" memory, regardless of any configured bulk size
 
-// -------------------------------------
+    The domains provided by this crate skip reclamation in [`reclaim`](`Domain::reclaim`) if there are fewer retired values than the configured [`bulk_size`](`crate::domains::Config::bulk_size`). This method reclaims memory regardless, which can be useful e.g. before shutting down. The default implementation simply calls [`reclaim`](`Domain::reclaim`).
 
-fn dummy_addr() -> usize {
-    static DUMMY: u8 = 0;
-    addr_of!(DUMMY) as usize
-}
+    The method must return the number of reclaimed objects
 
-/// Holds some address that is currently used
-pub struct HzrdPtr(AtomicUsize);
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
 
-impl HzrdPtr {
-    /// Create a new hazard pointer (it will already be acquired)
-    pub fn new() -> Self {
-        HzrdPtr(AtomicUsize::new(dummy_addr()))
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    cell.just_set(1);
+
+    assert_eq!(domain.reclaim_all(), 1);
+    ```
+    */
+    fn reclaim_all(&self) -> usize {
+        self.reclaim()
     }
 
-    /// Get the value held by the hazard pointer
-    pub fn get(&self) -> usize {
-        self.0.load(SeqCst)
+    /**
+    Reclaim "reclaimable" memory, but only if there are more than `threshold` retired values
+
+    This works like [`reclaim`](`Domain::reclaim`) with the given threshold instead of the configured [`bulk_size`](`crate::domains::Config::bulk_size`). It allows for adapting the reclamation policy at the call site, e.g. reclaiming eagerly when idle and lazily when busy, without changing the config. The default implementation ignores the threshold, and simply calls [`reclaim`](`Domain::reclaim`).
+
+    The method must return the number of reclaimed objects
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    for i in 1..=4 {
+        cell.just_set(i);
     }
 
-    /// Try to aquire the hazard pointer
-    pub fn try_acquire(&self) -> Option<&Self> {
-        match self.0.compare_exchange(0, dummy_addr(), SeqCst, Relaxed) {
-            Ok(_) => Some(self),
-            Err(_) => None,
-        }
+    assert_eq!(domain.reclaim_if_over(4), 0);
+    assert_eq!(domain.reclaim_if_over(3), 4);
+    ```
+    */
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        let _ = threshold;
+        self.reclaim()
     }
 
     /**
-    Protect the value behind this pointer
+    Reclaim "reclaimable" memory, but only check retired values against the hazard pointers at most `max_checks` times
 
-    # Safety
-    - The caller must be the current "owner" of the hazard pointer
-    - The caller must assert that the ptr did not change before the value was stored
-    - The pointer may not be null
+    Every retired value needs to be compared against every hazard pointer, which can take a while if there are many of each. This bounds the amount of work done, such that reclamation can be spread out over multiple calls. Any retired values which are not checked are left for the next call. The configured [`bulk_size`](`crate::domains::Config::bulk_size`) is ignored. The default implementation ignores the budget, and simply calls [`reclaim`](`Domain::reclaim`).
+
+    The method must return the number of reclaimed objects
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    for i in 1..=10 {
+        cell.just_set(i);
+    }
+
+    // Spread the reclamation out over multiple steps
+    while domain.retired_count() > 0 {
+        assert!(domain.try_reclaim_with_budget(4) <= 4);
+    }
+    ```
     */
-    pub unsafe fn protect<T>(&self, ptr: *mut T) {
-        debug_assert!(!ptr.is_null());
-        self.0.store(ptr as usize, SeqCst);
+    fn try_reclaim_with_budget(&self, max_checks: usize) -> usize {
+        let _ = max_checks;
+        self.reclaim()
     }
 
     /**
-    Reset the hazard pointer
+    Try to get a new hazard pointer in the given domain, without allocating
 
-    # Safety
-    - The caller must be the current "owner" of the hazard pointer
+    Domains with a bounded number of hazard pointers, such as [`FixedDomain`](`crate::domains::FixedDomain`), return `None` if all of them are in use. The default implementation simply calls [`hzrd_ptr`](`Domain::hzrd_ptr`), and so never returns `None`.
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::FixedDomain;
+
+    let domain = FixedDomain::<1>::new();
+    let hzrd_ptr = domain.try_hzrd_ptr();
+    assert!(hzrd_ptr.is_some());
+    assert!(domain.try_hzrd_ptr().is_none());
+    ```
     */
-    pub unsafe fn reset(&self) {
-        self.0.store(dummy_addr(), SeqCst);
+    fn try_hzrd_ptr(&self) -> Option<&HzrdPtr> {
+        Some(self.hzrd_ptr())
     }
 
     /**
-    Release the hazard pointer
+    Get the number of retired values waiting to be reclaimed
 
-    # Safety
-    - The caller must be the current "owner" of the hazard pointer
-    - The hazard cell must be re-aquired after calling this using [`try_acquire`](`HzrdPtr::try_acquire`)
-    */
-    pub unsafe fn release(&self) {
-        self.0.store(0, SeqCst);
-    }
-}
+    This can be used to monitor the buildup of garbage, for example to decide when to call [`reclaim`](`Domain::reclaim`). The count may be slightly outdated if other threads are retiring or reclaiming values at the same time. The default implementation always returns zero, as the count is unknown.
 
-impl Default for HzrdPtr {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
 
-impl std::fmt::Debug for HzrdPtr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "HzrdPtr({:#X})", self.0.load(Relaxed))
+    let handle = cell.read();
+    cell.set(1);
+    assert_eq!(domain.retired_count(), 1);
+
+    drop(handle);
+    cell.reclaim();
+    assert_eq!(domain.retired_count(), 0);
+    ```
+    */
+    fn retired_count(&self) -> usize {
+        0
     }
-}
 
-unsafe impl Send for HzrdPtr {}
-unsafe impl Sync for HzrdPtr {}
+    /**
+    Get a unique id for the domain
 
-// -------------------------------------
+    The id is used to check, in debug builds, that values are only protected by hazard pointers from the domain they belong to (see [`ReadHandle::read_unchecked_in`]). Zero means the domain has no id, which is what the default implementation returns. All the domains provided by this crate have an id.
 
-/// Custom trait meant to signify only that the value can be deleted
-trait Delete {}
-impl<T> Delete for T {}
+    A domain must not report the non-zero id of another domain, unless it only forwards to that domain (such as `&D` or `Arc<D>`). This is relied upon for safety by [`LocalReader::read`](`crate::domains::LocalReader::read`).
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+
+    let domain_1 = SharedDomain::new();
+    let domain_2 = SharedDomain::new();
+    assert_ne!(domain_1.domain_id(), domain_2.domain_id());
+    ```
+    */
+    fn domain_id(&self) -> usize {
+        0
+    }
+
+    /**
+    Get the number of hazard pointers allocated in the domain
+
+    This includes hazard pointers which are not currently in use. The domains provided by this crate reuse released hazard pointers, but never deallocate them before the domain itself is dropped, so the count only ever grows. A count that keeps growing is a sign of readers which never release their hazard pointers. The default implementation always returns zero, as the count is unknown.
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+
+    let handle_1 = cell.read();
+    let handle_2 = cell.read();
+    assert_eq!(domain.hzrd_ptr_count(), 2);
+
+    // The hazard pointers are released, but not deallocated
+    drop((handle_1, handle_2));
+    assert_eq!(domain.hzrd_ptr_count(), 2);
+    ```
+    */
+    fn hzrd_ptr_count(&self) -> usize {
+        0
+    }
+
+    /**
+    Get a snapshot of the hazard pointers and retired values of the domain
+
+    This is meant for observability, e.g. for exporting metrics. A number of active hazard pointers which never drops is a sign of readers which are never dropped, while a number of retired values which keeps growing is a sign of garbage building up. The numbers may be slightly outdated if other threads are using the domain at the same time. The default implementation is based on [`hzrd_ptr_count`](Domain::hzrd_ptr_count) and [`retired_count`](Domain::retired_count), and always reports zero active hazard pointers, as this is unknown.
+
+    # Example
+    ```
+    use hzrd::core::{Domain, DomainStats};
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+
+    let handle = cell.read();
+    drop(cell.read());
+    cell.just_set(1);
+
+    let stats = domain.stats();
+    assert_eq!(stats.hzrd_ptrs_total, 2);
+    assert_eq!(stats.hzrd_ptrs_active, 1);
+    assert_eq!(stats.retired_pending, 1);
+    # drop(handle);
+    ```
+    */
+    fn stats(&self) -> DomainStats {
+        DomainStats {
+            hzrd_ptrs_total: self.hzrd_ptr_count(),
+            hzrd_ptrs_active: 0,
+            retired_pending: self.retired_count(),
+        }
+    }
+
+    /**
+    Report all hazard pointers which have been protecting the same value for longer than the given duration
+
+    This is meant as a tool for tracking down readers holding on to values for too long, which keeps memory from being reclaimed. Hazard pointers are only timestamped in debug builds, so in release builds, or without the `std` feature, this will always return an empty list. The default implementation also returns an empty list.
+
+    # Example
+    ```
+    use std::time::Duration;
+
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+
+    let handle = cell.read();
+    std::thread::sleep(Duration::from_millis(10));
+
+    let leaks = domain.report_long_held(Duration::from_millis(5));
+    # #[cfg(debug_assertions)]
+    assert_eq!(leaks[0].addr, &*handle as *const i32 as usize);
+    ```
+    */
+    fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+        let _ = older_than;
+        Vec::new()
+    }
+
+    /**
+    Get the number of hazard pointers currently protecting the given address
+
+    This scans all the hazard pointers of the domain, just like a reclaim would, and so it's not free, but it doesn't touch any retired values. The count is a snapshot, and may be outdated as soon as it's returned. The default implementation always returns zero, as the hazard pointers are unknown.
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+
+    let handle = cell.read();
+    assert_eq!(domain.protection_count(cell.as_ptr() as usize), 1);
+    drop(handle);
+    assert_eq!(domain.protection_count(cell.as_ptr() as usize), 0);
+    ```
+    */
+    fn protection_count(&self, addr: usize) -> usize {
+        let _ = addr;
+        0
+    }
+}
+
+// https://stackoverflow.com/questions/63963544/automatically-derive-traits-implementation-for-arc
+macro_rules! deref_impl {
+    ($($sig:tt)+) => {
+        unsafe impl $($sig)+ {
+            fn hzrd_ptr(&self) -> &HzrdPtr {
+                (**self).hzrd_ptr()
+            }
+
+            fn just_retire(&self, ret_ptr: RetiredPtr) {
+                (**self).just_retire(ret_ptr);
+            }
+
+            fn reclaim(&self) -> usize {
+                (**self).reclaim()
+            }
+
+            fn reclaim_all(&self) -> usize {
+                (**self).reclaim_all()
+            }
+
+            fn reclaim_if_over(&self, threshold: usize) -> usize {
+                (**self).reclaim_if_over(threshold)
+            }
+
+            fn try_reclaim_with_budget(&self, max_checks: usize) -> usize {
+                (**self).try_reclaim_with_budget(max_checks)
+            }
+
+            fn try_hzrd_ptr(&self) -> Option<&HzrdPtr> {
+                (**self).try_hzrd_ptr()
+            }
+
+            fn retired_count(&self) -> usize {
+                (**self).retired_count()
+            }
+
+            fn domain_id(&self) -> usize {
+                (**self).domain_id()
+            }
+
+            fn hzrd_ptr_count(&self) -> usize {
+                (**self).hzrd_ptr_count()
+            }
+
+            fn stats(&self) -> DomainStats {
+                (**self).stats()
+            }
+
+            fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+                (**self).report_long_held(older_than)
+            }
+
+            fn protection_count(&self, addr: usize) -> usize {
+                (**self).protection_count(addr)
+            }
+        }
+    };
+}
+
+deref_impl!(<D: Domain> Domain for &D);
+deref_impl!(<D: Domain> Domain for Rc<D>);
+deref_impl!(<D: Domain> Domain for Arc<D>);
+
+// -------------------------------------
+
+fn dummy_addr() -> usize {
+    static DUMMY: u8 = 0;
+    addr_of!(DUMMY) as usize
+}
+
+/// Time passed since some fixed point, used for timestamping hazard pointers
+#[cfg(debug_assertions)]
+fn timestamp() -> Duration {
+    #[cfg(feature = "std")]
+    {
+        static EPOCH: OnceLock<Instant> = OnceLock::new();
+        EPOCH.get_or_init(Instant::now).elapsed()
+    }
+
+    // There is no clock without the standard library, and so no time ever passes
+    #[cfg(not(feature = "std"))]
+    Duration::ZERO
+}
+
+/// Holds some address that is currently used
+pub struct HzrdPtr {
+    addr: AtomicUsize,
+    /// Timestamp (in nanoseconds) of when the current address was protected, zero if none
+    #[cfg(debug_assertions)]
+    protected_since: AtomicU64,
+    /// Id of the domain the hazard pointer belongs to, zero if unknown
+    #[cfg(debug_assertions)]
+    domain_id: usize,
+}
+
+impl HzrdPtr {
+    /// Create a new hazard pointer (it will already be acquired)
+    pub fn new() -> Self {
+        Self::new_in_domain(0)
+    }
+
+    /// Create a new hazard pointer belonging to the domain with the given id
+    pub(crate) fn new_in_domain(domain_id: usize) -> Self {
+        #[cfg(not(debug_assertions))]
+        let _ = domain_id;
+
+        HzrdPtr {
+            addr: AtomicUsize::new(dummy_addr()),
+            #[cfg(debug_assertions)]
+            protected_since: AtomicU64::new(0),
+            #[cfg(debug_assertions)]
+            domain_id,
+        }
+    }
+
+    /**
+    Get the id of the domain the hazard pointer belongs to
+
+    Hazard pointers are only tagged with the id of their domain in debug builds, and so this always returns zero (meaning unknown) in release builds. See [`Domain::domain_id`].
+    */
+    pub fn domain_id(&self) -> usize {
+        #[cfg(debug_assertions)]
+        return self.domain_id;
+
+        #[cfg(not(debug_assertions))]
+        0
+    }
+
+    /// Assert that the hazard pointer belongs to the given domain (only in debug builds)
+    pub(crate) fn debug_assert_domain<D: Domain + ?Sized>(&self, domain: &D) {
+        #[cfg(debug_assertions)]
+        {
+            let (expected, actual) = (domain.domain_id(), self.domain_id);
+            assert!(
+                expected == 0 || actual == 0 || expected == actual,
+                "Hazard pointer from domain {actual} used for a value in domain {expected}"
+            );
+        }
+
+        #[cfg(not(debug_assertions))]
+        let _ = domain;
+    }
+
+    /// Get the value held by the hazard pointer
+    pub fn get(&self) -> usize {
+        self.addr.load(SeqCst)
+    }
+
+    /**
+    Get the address currently protected by the hazard pointer, if any
+
+    A hazard pointer which is free holds the value zero, while one which is acquired, but not protecting anything, holds the address of a private dummy value. Neither of these are returned.
+    */
+    pub(crate) fn protected_addr(&self) -> Option<usize> {
+        match self.get() {
+            0 => None,
+            addr if addr == dummy_addr() => None,
+            addr => Some(addr),
+        }
+    }
+
+    /// Try to aquire the hazard pointer
+    pub fn try_acquire(&self) -> Option<&Self> {
+        match self.addr.compare_exchange(0, dummy_addr(), SeqCst, Relaxed) {
+            Ok(_) => Some(self),
+            Err(_) => None,
+        }
+    }
+
+    /**
+    Try to claim an acquired hazard pointer which is not protecting anything, by protecting the given pointer
+
+    This lets the owner of a set of hazard pointers hand them out to multiple users at once. A hazard pointer which has been reset is free to be claimed, while one which is protecting a value is busy until it is reset again.
+
+    # Safety
+    - The caller must be an owner of the hazard pointer, sharing it only through this function
+    - The caller must verify that the pointer did not change before the value is used, such as by reading it with [`ReadHandle::read_unchecked`]
+    */
+    pub(crate) unsafe fn try_claim<T>(&self, ptr: NonNull<T>) -> bool {
+        let addr = ptr.as_ptr() as usize;
+        let claimed = self
+            .addr
+            .compare_exchange(dummy_addr(), addr, SeqCst, Relaxed)
+            .is_ok();
+
+        #[cfg(debug_assertions)]
+        if claimed {
+            let now = timestamp().as_nanos() as u64;
+            self.protected_since.store(now.max(1), SeqCst);
+        }
+
+        claimed
+    }
+
+    /**
+    Get for how long the hazard pointer has been protecting its current value
+
+    Returns `None` if the hazard pointer is not protecting any value. Hazard pointers are only timestamped in debug builds, and so this always returns `None` in release builds. Without the `std` feature there is no clock, and the duration is always zero.
+    */
+    pub fn protected_for(&self) -> Option<Duration> {
+        #[cfg(debug_assertions)]
+        {
+            let since = self.protected_since.load(SeqCst);
+            if since == 0 || self.protected_addr().is_none() {
+                return None;
+            }
+
+            Some(timestamp().saturating_sub(Duration::from_nanos(since)))
+        }
+
+        #[cfg(not(debug_assertions))]
+        None
+    }
+
+    /// Get info about the hazard pointer if it has been protecting the same value for longer than the given duration
+    pub(crate) fn leak_info(&self, older_than: Duration) -> Option<LeakInfo> {
+        let held_for = self.protected_for()?;
+        (held_for > older_than).then(|| LeakInfo {
+            addr: self.get(),
+            held_for,
+        })
+    }
+
+    /**
+    Protect the value behind this pointer
+
+    See also [`protect_nonnull`](HzrdPtr::protect_nonnull), which can not be given a null pointer.
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The caller must assert that the ptr did not change before the value was stored
+    - The pointer may not be null
+    */
+    pub unsafe fn protect<T>(&self, ptr: *mut T) {
+        debug_assert!(!ptr.is_null());
+
+        // SAFETY: The caller guarantees that the pointer is not null
+        unsafe { self.protect_nonnull(NonNull::new_unchecked(ptr)) }
+    }
+
+    /**
+    Protect the value behind this non-null pointer
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The caller must assert that the ptr did not change before the value was stored
+    */
+    pub unsafe fn protect_nonnull<T>(&self, ptr: NonNull<T>) {
+        let addr = ptr.as_ptr() as usize;
+
+        #[cfg(not(debug_assertions))]
+        self.addr.store(addr, SeqCst);
+
+        // Only update the timestamp if a new value is protected
+        #[cfg(debug_assertions)]
+        if self.addr.swap(addr, SeqCst) != addr {
+            let now = timestamp().as_nanos() as u64;
+            self.protected_since.store(now.max(1), SeqCst);
+        }
+    }
+
+    /**
+    Load the value of an atomic pointer, and protect it with this hazard pointer
+
+    This keeps loading the pointer until it has been protected without being changed in the meantime. The returned reference is valid for as long as the value stays protected, which is up to the caller. This is the building block of [`ReadHandle::read_unchecked`], and can be used for custom data structures which manage the hazard pointer themselves.
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The value of the atomic pointer may not be null, and must be retired to the domain of the hazard pointer
+    - The hazard pointer must not be reset, released or used to protect another value while the reference is in use
+
+    # Example
+    ```
+    use std::sync::atomic::{AtomicPtr, Ordering::*};
+
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+
+    let domain = SharedDomain::new();
+    let value = AtomicPtr::new(Box::into_raw(Box::new(String::from("Hello"))));
+
+    let hzrd_ptr = domain.hzrd_ptr();
+    let string = unsafe { hzrd_ptr.load_protected(&value) };
+    assert_eq!(string, "Hello");
+    unsafe { hzrd_ptr.release() };
+
+    # let _ = unsafe { Box::from_raw(value.load(SeqCst)) };
+    ```
+    */
+    pub unsafe fn load_protected<'t, T>(&self, value: &'t AtomicPtr<T>) -> &'t T {
+        // SAFETY: The caller guarantees that the atomic pointer is never null
+        unsafe { self.try_load_protected(value).unwrap_unchecked() }
+    }
+
+    /**
+    Load the value of an atomic pointer, and protect it with this hazard pointer, unless it is null
+
+    This is the same as [`load_protected`](HzrdPtr::load_protected), except that the atomic pointer may be null, in which case `None` is returned and nothing is protected.
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The values of the atomic pointer must be retired to the domain of the hazard pointer
+    - The hazard pointer must not be reset, released or used to protect another value while the reference is in use
+    */
+    pub unsafe fn try_load_protected<'t, T>(&self, value: &'t AtomicPtr<T>) -> Option<&'t T> {
+        let mut ptr = NonNull::new(value.load(SeqCst))?;
+        loop {
+            // SAFETY: We are the owner of the hazard pointer
+            unsafe { self.protect_nonnull(ptr) };
+
+            // The protection must be visible to writers before the pointer is verified
+            fence(SeqCst);
+
+            // We now need to keep updating it until it is in a consistent state
+            let new_ptr = NonNull::new(value.load(SeqCst))?;
+            if ptr == new_ptr {
+                break;
+            } else {
+                ptr = new_ptr;
+            }
+        }
+
+        // SAFETY: This pointer is now held valid by the hazard pointer
+        Some(unsafe { ptr.as_ref() })
+    }
+
+    /**
+    Reset the hazard pointer
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    */
+    pub unsafe fn reset(&self) {
+        self.addr.store(dummy_addr(), SeqCst);
+        #[cfg(debug_assertions)]
+        self.protected_since.store(0, SeqCst);
+    }
+
+    /**
+    Release the hazard pointer
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The hazard cell must be re-aquired after calling this using [`try_acquire`](`HzrdPtr::try_acquire`)
+    */
+    pub unsafe fn release(&self) {
+        #[cfg(debug_assertions)]
+        self.protected_since.store(0, SeqCst);
+        self.addr.store(0, SeqCst);
+    }
+}
+
+impl Default for HzrdPtr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for HzrdPtr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "HzrdPtr({:#X})", self.addr.load(Relaxed))
+    }
+}
+
+unsafe impl Send for HzrdPtr {}
+unsafe impl Sync for HzrdPtr {}
+
+/// Info about a hazard pointer which has been protecting the same value for a long time
+///
+/// See [`Domain::report_long_held`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeakInfo {
+    /// The address of the protected value
+    pub addr: usize,
+    /// How long the value has been protected
+    pub held_for: Duration,
+}
+
+/// Snapshot of the state of a domain
+///
+/// See [`Domain::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DomainStats {
+    /// The number of hazard pointers allocated in the domain
+    pub hzrd_ptrs_total: usize,
+    /// The number of hazard pointers currently in use
+    pub hzrd_ptrs_active: usize,
+    /// The number of retired values waiting to be reclaimed
+    pub retired_pending: usize,
+}
+
+/**
+Iterator over a linked structure, protecting each node before it's read
+
+This packages the "hand-over-hand" traversal of lock-free linked structures, such as linked lists. Each node is protected before it's dereferenced, and the link to the next node is only followed once that node is protected in turn. Two hazard pointers are used in alternation, since the current node must stay protected while the link to the next one is verified.
+
+# Example
+```
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering::*};
+
+use hzrd::core::{Domain, ProtectedIter};
+use hzrd::domains::SharedDomain;
+
+struct Node {
+    value: i32,
+    next: AtomicPtr<Node>,
+}
+
+let node = |value, next| Box::into_raw(Box::new(Node { value, next: AtomicPtr::new(next) }));
+let head = AtomicPtr::new(node(1, node(2, node(3, null_mut()))));
+
+let domain = SharedDomain::new();
+let hzrd_ptrs = [domain.hzrd_ptr(), domain.hzrd_ptr()];
+let iter = unsafe { ProtectedIter::new(&head, hzrd_ptrs, |node: &Node| &node.next) };
+assert_eq!(iter.map(|node| node.value).collect::<Vec<_>>(), [1, 2, 3]);
+
+for hzrd_ptr in hzrd_ptrs {
+    unsafe { hzrd_ptr.release() };
+}
+
+# let mut ptr = head.load(SeqCst);
+# while !ptr.is_null() {
+#     let node = unsafe { Box::from_raw(ptr) };
+#     ptr = node.next.load(SeqCst);
+# }
+```
+*/
+pub struct ProtectedIter<'t, T, F> {
+    link: Option<&'t AtomicPtr<T>>,
+    hzrd_ptrs: [&'t HzrdPtr; 2],
+    next: F,
+}
+
+impl<'t, T, F> ProtectedIter<'t, T, F>
+where
+    F: FnMut(&'t T) -> &'t AtomicPtr<T>,
+{
+    /**
+    Construct a new iterator, starting at the given head, and following links with the given function
+
+    The iteration stops once a null link is encountered. The hazard pointers are left protecting the last nodes when the iterator is dropped, it's up to the caller to reset or release them.
+
+    # Safety
+    - The caller must be the current "owner" of both hazard pointers, and they must not be used for anything else while the iterator, or any reference from it, is in use
+    - All nodes must be allocated by [`Box`], and must be retired to the domain of the hazard pointers
+    - A node must not be retired while it's still reachable through the link of another node, even if that node is itself removed from the structure
+    - Each reference returned by the iterator must not be used after the next call to [`next`](Iterator::next)
+    */
+    pub unsafe fn new(head: &'t AtomicPtr<T>, hzrd_ptrs: [&'t HzrdPtr; 2], next: F) -> Self {
+        Self {
+            link: Some(head),
+            hzrd_ptrs,
+            next,
+        }
+    }
+}
+
+impl<'t, T, F> Iterator for ProtectedIter<'t, T, F>
+where
+    F: FnMut(&'t T) -> &'t AtomicPtr<T>,
+{
+    type Item = &'t T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let link = self.link.take()?;
+
+        // SAFETY: The link is held by the previous node, which is still protected by the other hazard pointer
+        let node = unsafe { self.hzrd_ptrs[0].try_load_protected(link) }?;
+
+        // The hazard pointer protecting the previous node is used for the next one
+        self.hzrd_ptrs.swap(0, 1);
+        self.link = Some((self.next)(node));
+        Some(node)
+    }
+}
+
+impl<T, F> core::fmt::Debug for ProtectedIter<'_, T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProtectedIter").finish_non_exhaustive()
+    }
+}
+
+// -------------------------------------
+
+/// Drop a value allocated with [`Box`]
+///
+/// SAFETY: The pointer must come from a [`Box<T>`], and no reference to the value can be alive
+unsafe fn drop_box<T>(ptr: NonNull<()>) {
+    // SAFETY: Guaranteed by the caller
+    let _: Box<T> = unsafe { Box::from_raw(ptr.as_ptr() as *mut T) };
+}
 
 /// A pointer that will free the underlying value on drop
 pub struct RetiredPtr {
-    ptr: NonNull<dyn Delete>,
+    ptr: NonNull<()>,
+    drop_fn: unsafe fn(NonNull<()>),
+    // The type id of the value, used for recovering the type of a retired pointer
+    type_id: Option<TypeId>,
+    // The number of reclamations the value has been protected through, only tracked in debug builds (for warning about it)
+    #[cfg(all(debug_assertions, feature = "std"))]
+    protected_through: AtomicUsize,
 }
 
 impl RetiredPtr {
@@ -305,24 +1278,95 @@ impl RetiredPtr {
     - The pointer must be held alive until it is safe to drop
     */
     pub unsafe fn new<T: 'static>(ptr: NonNull<T>) -> Self {
-        RetiredPtr { ptr }
+        RetiredPtr {
+            ptr: ptr.cast(),
+            drop_fn: drop_box::<T>,
+            type_id: Some(TypeId::of::<T>()),
+            #[cfg(all(debug_assertions, feature = "std"))]
+            protected_through: AtomicUsize::new(0),
+        }
     }
 
-    /// Get the address of the retired pointer
+    /**
+    Create a new retired pointer, which is freed by calling the given function
+
+    This allows for retiring values which are not allocated with [`Box`], such as values from a custom allocator or slots in a slab. The function is called with the given pointer once the value is no longer protected by any hazard pointers.
+
+    # Safety
+    - Calling the function with the given pointer must be safe once no reference to the value is alive
+    - The pointer must be held alive until it is safe to drop
+
+    # Example
+    ```
+    use std::ptr::NonNull;
+
+    use hzrd::core::{Domain, RetiredPtr};
+    use hzrd::domains::SharedDomain;
+
+    unsafe fn drop_string(ptr: NonNull<()>) {
+        let _ = unsafe { Box::from_raw(ptr.cast::<String>().as_ptr()) };
+    }
+
+    let domain = SharedDomain::new();
+    let ptr = NonNull::from(Box::leak(Box::new(String::from("Hello")))).cast();
+    domain.retire(unsafe { RetiredPtr::with_dropper(ptr, drop_string) });
+    ```
+    */
+    pub unsafe fn with_dropper(ptr: NonNull<()>, drop_fn: unsafe fn(NonNull<()>)) -> Self {
+        RetiredPtr {
+            ptr,
+            drop_fn,
+            type_id: None,
+            #[cfg(all(debug_assertions, feature = "std"))]
+            protected_through: AtomicUsize::new(0),
+        }
+    }
+
+    /**
+    Get the address of the retired pointer
+
+    The address is only used for comparing against the addresses held by hazard pointers, see [`HzrdPtr::get`]. Addresses are never converted back into pointers, and so they don't need to carry any provenance. The value is only ever accessed through the original pointer.
+    */
     pub fn addr(&self) -> usize {
-        self.ptr.as_ptr() as *mut () as usize
+        self.ptr.as_ptr() as usize
+    }
+
+    /// Record that the value was still protected during a reclamation, returning the number of times this has happened
+    #[cfg(all(debug_assertions, feature = "std"))]
+    pub(crate) fn protected_through_reclaim(&self) -> usize {
+        self.protected_through.fetch_add(1, Relaxed) + 1
+    }
+
+    /**
+    Recover the boxed value, if it's of the given type
+
+    Pointers retired with a custom destructor are never recovered.
+
+    # Safety
+    - The value can not be protected by any hazard pointers
+    */
+    pub(crate) unsafe fn downcast<T: 'static>(self) -> Result<Box<T>, Self> {
+        if self.type_id != Some(TypeId::of::<T>()) {
+            return Err(self);
+        }
+
+        let ptr = self.ptr.as_ptr() as *mut T;
+        core::mem::forget(self);
+
+        // SAFETY: The type was checked above, and the value is always heap-allocated
+        Ok(unsafe { Box::from_raw(ptr) })
     }
 }
 
 impl Drop for RetiredPtr {
     fn drop(&mut self) {
-        // SAFETY: No reference to this when dropped (and always heap allocated)
-        let _: Box<dyn Delete> = unsafe { Box::from_raw(self.ptr.as_ptr()) };
+        // SAFETY: No reference to this when dropped
+        unsafe { (self.drop_fn)(self.ptr) };
     }
 }
 
-impl std::fmt::Debug for RetiredPtr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for RetiredPtr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "RetiredPtr({:#X})", self.addr())
     }
 }
@@ -348,6 +1392,30 @@ mod tests {
         unsafe { hzrd_ptr.protect(&mut value) };
     }
 
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn protected_for() {
+        let mut value = 0;
+        let hzrd_ptr = HzrdPtr::new();
+        assert_eq!(hzrd_ptr.protected_for(), None);
+
+        unsafe { hzrd_ptr.protect(&mut value) };
+        std::thread::sleep(Duration::from_millis(10));
+        let held_for = hzrd_ptr.protected_for().unwrap();
+        assert!(held_for >= Duration::from_millis(10));
+
+        // Protecting the same value again should not reset the timestamp
+        unsafe { hzrd_ptr.protect(&mut value) };
+        assert!(hzrd_ptr.protected_for().unwrap() >= held_for);
+
+        let info = hzrd_ptr.leak_info(Duration::from_millis(5)).unwrap();
+        assert_eq!(info.addr, &value as *const i32 as usize);
+        assert!(hzrd_ptr.leak_info(Duration::from_secs(60)).is_none());
+
+        unsafe { hzrd_ptr.reset() };
+        assert_eq!(hzrd_ptr.protected_for(), None);
+    }
+
     #[test]
     fn retired_ptr() {
         let object = vec![String::from("Hello"), String::from("World")];
@@ -357,4 +1425,155 @@ mod tests {
         let retired = unsafe { RetiredPtr::new(ptr) };
         drop(retired);
     }
+
+    #[test]
+    fn retire_and_reclaim() {
+        use crate::domains::SharedDomain;
+
+        let domain = SharedDomain::new();
+        let value = AtomicPtr::new(Box::into_raw(Box::new(String::from("Hello"))));
+
+        let hzrd_ptr = domain.hzrd_ptr();
+        let handle =
+            unsafe { ReadHandle::read_unchecked_in(&value, hzrd_ptr, Action::Reset, &domain) };
+
+        let new_ptr = Box::into_raw(Box::new(String::from("World")));
+        let old_ptr = NonNull::new(value.swap(new_ptr, SeqCst)).unwrap();
+        domain.just_retire(unsafe { RetiredPtr::new(old_ptr) });
+
+        // The address of the retired pointer matches the hazard pointer, and so it's kept
+        assert_eq!(domain.reclaim(), 0);
+        assert_eq!(*handle, "Hello");
+
+        drop(handle);
+        assert_eq!(domain.reclaim(), 1);
+
+        let _ = unsafe { Box::from_raw(value.load(SeqCst)) };
+    }
+
+    #[test]
+    fn protected_iter() {
+        use crate::domains::SharedDomain;
+
+        struct Node {
+            value: i32,
+            next: AtomicPtr<Node>,
+        }
+
+        let node = |value, next| {
+            let next = AtomicPtr::new(next);
+            Box::into_raw(Box::new(Node { value, next }))
+        };
+
+        let domain = SharedDomain::new();
+        let third = node(3, std::ptr::null_mut());
+        let second = node(2, third);
+        let head = AtomicPtr::new(node(1, second));
+
+        let hzrd_ptrs = [domain.hzrd_ptr(), domain.hzrd_ptr()];
+        let mut iter = unsafe { ProtectedIter::new(&head, hzrd_ptrs, |node: &Node| &node.next) };
+        let first = iter.next().unwrap();
+        assert_eq!(first.value, 1);
+        assert_eq!(iter.next().unwrap().value, 2);
+
+        // Unlink and retire the second node, which is still protected by the iterator
+        first.next.store(third, SeqCst);
+        domain.just_retire(unsafe { RetiredPtr::new(NonNull::new(second).unwrap()) });
+        assert_eq!(domain.reclaim(), 0);
+
+        // The removed node still links to the rest of the list
+        assert_eq!(iter.next().unwrap().value, 3);
+        assert!(iter.next().is_none());
+
+        // The hazard pointers keep protecting the last nodes until they're released
+        assert_eq!(domain.reclaim(), 0);
+        for hzrd_ptr in hzrd_ptrs {
+            unsafe { hzrd_ptr.release() };
+        }
+        assert_eq!(domain.reclaim(), 1);
+
+        let mut ptr = head.load(SeqCst);
+        while !ptr.is_null() {
+            let node = unsafe { Box::from_raw(ptr) };
+            ptr = node.next.load(SeqCst);
+        }
+    }
+
+    #[test]
+    fn downcast() {
+        let ptr = NonNull::from(Box::leak(Box::new(vec![1, 2, 3])));
+
+        // SAFETY: ptr is heap-allocated, and not protected
+        let retired = unsafe { RetiredPtr::new(ptr) };
+        let retired = unsafe { retired.downcast::<String>() }.unwrap_err();
+        let boxed = unsafe { retired.downcast::<Vec<i32>>() }.unwrap();
+        assert_eq!(*boxed, [1, 2, 3]);
+    }
+
+    #[test]
+    fn with_dropper() {
+        use std::sync::atomic::AtomicUsize;
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        unsafe fn drop_slot(ptr: NonNull<()>) {
+            DROPPED.fetch_add(1, SeqCst);
+            let _ = unsafe { Box::from_raw(ptr.cast::<[u8; 16]>().as_ptr()) };
+        }
+
+        let ptr = NonNull::from(Box::leak(Box::new([0u8; 16])));
+
+        // SAFETY: drop_slot frees the value as it was allocated
+        let retired = unsafe { RetiredPtr::with_dropper(ptr.cast(), drop_slot) };
+        assert_eq!(retired.addr(), ptr.as_ptr() as usize);
+
+        // Values with a custom destructor are never recovered
+        let retired = unsafe { retired.downcast::<[u8; 16]>() }.unwrap_err();
+        assert_eq!(DROPPED.load(SeqCst), 0);
+        drop(retired);
+        assert_eq!(DROPPED.load(SeqCst), 1);
+    }
+
+    #[test]
+    fn map_handle() {
+        let domain = crate::domains::SharedDomain::new();
+        let cell = crate::HzrdCell::new_in((0, String::from("Hello")), &domain);
+
+        let handle = ReadHandle::map(cell.read(), |(_, string)| string);
+        cell.set((1, String::from("World")));
+
+        // The mapped handle still protects the whole value
+        assert_eq!(domain.retired_count(), 1);
+        assert_eq!(*handle, "Hello");
+
+        drop(handle);
+        cell.reclaim();
+        assert_eq!(domain.retired_count(), 0);
+    }
+
+    #[test]
+    fn try_map_handle() {
+        let domain = crate::domains::SharedDomain::new();
+        let cell = crate::HzrdCell::new_in(None::<String>, &domain);
+
+        // A failed projection hands back the handle, which still protects the value
+        let handle = ReadHandle::try_map(cell.read(), Option::as_ref)
+            .err()
+            .unwrap();
+        cell.set(Some(String::from("Hello")));
+        assert_eq!(domain.retired_count(), 1);
+        assert!(handle.is_none());
+        drop(handle);
+
+        let handle = ReadHandle::try_map(cell.read(), Option::as_ref)
+            .ok()
+            .unwrap();
+        cell.set(None);
+        assert_eq!(domain.retired_count(), 1);
+        assert_eq!(*handle, "Hello");
+
+        drop(handle);
+        cell.reclaim();
+        assert_eq!(domain.retired_count(), 0);
+    }
 }
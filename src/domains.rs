@@ -6,54 +6,82 @@ The module has three core types:
 - [`SharedDomain`]: A multithreaded, shared domain
 - [`LocalDomain`]: A singlethreaded, local domain
 
+There is also the [`RecyclingDomain`], a variant of [`SharedDomain`] which allows reclaimed values to be reused, the [`FixedDomain`], which has a fixed number of hazard pointers, the [`NoReclaimDomain`], which only frees memory when it's dropped, and the [`GenerationDomain`], where readers announce a generation such that older values can be reclaimed without looking up their addresses.
+
 The default domain used by [`HzrdCell`](`crate::HzrdCell`) is [`GlobalDomain`], which is the recommended domain for most applications.
 */
 
 // -------------------------------------
 
-use std::cell::{Cell, UnsafeCell};
-use std::collections::LinkedList;
-use std::sync::OnceLock;
-
-use crate::core::{Domain, HzrdPtr, RetiredPtr};
+use alloc::boxed::Box;
+use alloc::collections::LinkedList;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering::*;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::{cell::Cell, sync::OnceLock};
+
+use crate::core::{Action, Domain, DomainStats, HzrdPtr, LeakInfo, ReadHandle, RetiredPtr};
 use crate::stack::SharedStack;
+use crate::sync::{fence, AtomicUsize};
+use crate::HzrdCell;
 
 // -------------------------------------
 
 /**
 This variable can be used to configure the behavior of the domains provided by this crate
 
-The variable can only be set once, and this must happen before any operation on any of the domains. If the variable has not been configured before the first access, then the default value is used instead (see [`Config::default`]). The variable uses a standard [`OnceLock`](`std::sync::OnceLock`), and can be used as such:
+The variable can only be set once, and this must happen before any operation on any of the domains. If the variable has not been configured before the first access, then the default value is used instead (see [`Config::default`]). The variable uses a standard [`OnceLock`](`std::sync::OnceLock`), and is therefore only available with the `std` feature. Without it all domains which are not given their own config use the default config. The variable can be used as such:
 ```
 # use hzrd::domains::{Config, GLOBAL_CONFIG};
 let config = Config::default().caching(true);
 GLOBAL_CONFIG.set(config).unwrap();
 ```
 */
+#[cfg(feature = "std")]
 pub static GLOBAL_CONFIG: OnceLock<Config> = OnceLock::new();
 
+#[cfg(feature = "std")]
 fn global_config() -> &'static Config {
     GLOBAL_CONFIG.get_or_init(Config::default)
 }
 
+#[cfg(not(feature = "std"))]
+fn global_config() -> &'static Config {
+    &Config::DEFAULT
+}
+
 /**
 Config options for domains in this module
 
-If you want to change the global config options then this can be done via [`GLOBAL_CONFIG`]
+If you want to change the global config options then this can be done via [`GLOBAL_CONFIG`]. A single domain can instead be given its own config through [`SharedDomain::with_config`] or [`LocalDomain::with_config`], in which case the global config is ignored for that domain.
 */
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Config {
     caching: bool,
     bulk_size: usize,
+    preallocate: usize,
+    reclaim_strategy: ReclaimStrategy,
+    warn_long_held: usize,
     /*
     Other possible config options:
       - Maximum/fixed size cache
-      - Pre-allocate cache?
     */
 }
 
 impl Config {
-    /// Enable/disable caching (default: `false`)
+    const DEFAULT: Self = Self {
+        caching: false,
+        bulk_size: 1,
+        preallocate: 0,
+        reclaim_strategy: ReclaimStrategy::Auto,
+        warn_long_held: 0,
+    };
+
+    /// Enable/disable caching (default: `false`), the cache is thread-local and is ignored without the `std` feature
     pub fn caching(self, caching: bool) -> Self {
         Self { caching, ..self }
     }
@@ -90,44 +118,219 @@ impl Config {
     pub fn bulk_size(self, bulk_size: usize) -> Self {
         Self { bulk_size, ..self }
     }
+
+    /**
+    Set the number of hazard pointers to allocate up front (default: `0`)
+
+    Domains usually allocate hazard pointers as they are needed, which means the first reads are slower than the rest. The hazard pointers are preallocated when a domain is constructed with this config, e.g. via [`SharedDomain::with_config`]. Note that this does not apply to domains using [`GLOBAL_CONFIG`].
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::{Config, SharedDomain};
+
+    let domain = SharedDomain::with_config(Config::default().preallocate(4));
+    assert_eq!(domain.hzrd_ptr_count(), 4);
+    ```
+    */
+    pub fn preallocate(self, preallocate: usize) -> Self {
+        Self {
+            preallocate,
+            ..self
+        }
+    }
+
+    /**
+    Set the strategy used to match retired pointers against hazard pointers (default: [`ReclaimStrategy::Auto`])
+
+    See [`ReclaimStrategy`] for the available strategies.
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::{Config, ReclaimStrategy, SharedDomain};
+    use hzrd::HzrdCell;
+
+    let config = Config::default().reclaim_strategy(ReclaimStrategy::Sorted);
+    let domain = SharedDomain::with_config(config);
+    let cell = HzrdCell::new_in(0, &domain);
+
+    let handle = cell.read();
+    cell.just_set(1);
+    cell.just_set(2);
+    assert_eq!(domain.reclaim(), 1);
+    # drop(handle);
+    ```
+    */
+    pub fn reclaim_strategy(self, reclaim_strategy: ReclaimStrategy) -> Self {
+        Self {
+            reclaim_strategy,
+            ..self
+        }
+    }
+
+    /**
+    Warn when a retired value is still protected after the given number of reclamations (default: `0`, meaning never)
+
+    A [`ReadHandle`] which is held on to while the cell is written to keeps the old value from being reclaimed, and with it all the memory it owns. This is a diagnostic for finding such handles: When a retired value has been protected through this many attempts at reclaiming it, a warning with its address is printed to stderr. With the default bulk size the domains try to reclaim on every write, and so this is roughly the number of writes the handle has been held across. The warning is printed once per value.
+
+    Retired values are only tracked in debug builds, and the warning is printed to the standard error, so in release builds, or without the `std` feature, this does nothing.
+
+    # Example
+    ```
+    use hzrd::domains::{Config, SharedDomain};
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::with_config(Config::default().warn_long_held(100));
+    let cell = HzrdCell::new_in(0, &domain);
+
+    // Holding on to the handle across all these writes prints a warning
+    let handle = cell.read();
+    for i in 1..=100 {
+        cell.set(i);
+    }
+    # drop(handle);
+    ```
+    */
+    pub fn warn_long_held(self, reclaims: usize) -> Self {
+        Self {
+            warn_long_held: reclaims,
+            ..self
+        }
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
-        Self {
-            caching: false,
-            bulk_size: 1,
-        }
+        Self::DEFAULT
     }
 }
 
+/**
+Strategy for checking whether retired pointers are protected by any hazard pointer
+
+When reclaiming, every retired pointer is checked against the addresses of all hazard pointers in the domain. Which strategy is best depends on the number of hazard pointers: For a handful of hazard pointers a linear scan is the fastest, while domains with many hazard pointers, and many retired pointers, benefit from the upfront cost of building a lookup structure.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReclaimStrategy {
+    /// Use [`Linear`](ReclaimStrategy::Linear) for small domains, and [`Hashed`](ReclaimStrategy::Hashed) once there are more than a few dozen hazard pointers
+    Auto,
+    /// Scan all the hazard pointers for each retired pointer, O(retired × hazard)
+    Linear,
+    /// Sort the hazard pointers once, and binary search for each retired pointer, O((retired + hazard) × log(hazard))
+    Sorted,
+    /// Collect the hazard pointers in a [`HashSet`](std::collections::HashSet), and look up each retired pointer, O(retired + hazard)
+    ///
+    /// Without the `std` feature a [`BTreeSet`](alloc::collections::BTreeSet) is used instead, O((retired + hazard) × log(hazard))
+    Hashed,
+}
+
+/// Set of addresses, used for looking up hazard pointers
+#[cfg(feature = "std")]
+type AddrSet = std::collections::HashSet<usize>;
+
+/// Set of addresses, used for looking up hazard pointers
+#[cfg(not(feature = "std"))]
+type AddrSet = alloc::collections::BTreeSet<usize>;
+
 // -------------------------------------
 
+#[cfg(feature = "std")]
 thread_local! {
     static HAZARD_POINTERS_CACHE: Cell<Vec<usize>> = const { Cell::new(Vec::new()) };
+    static HAZARD_POINTERS_SET_CACHE: Cell<AddrSet> = Cell::new(AddrSet::new());
+}
+
+/**
+Clear and deallocate the hazard pointer cache of the current thread
+
+When [caching](Config::caching) is enabled, each thread keeps the buffer used to load the hazard pointers during reclamation. This buffer grows to fit the largest domain reclaimed on the thread, and is never shrunk on its own. Long-lived threads can call this function after working with a large domain to return the memory. The cache is simply rebuilt on the next reclamation.
+
+# Example
+```
+# use hzrd::domains::{flush_thread_cache, Config, SharedDomain};
+# use hzrd::HzrdCell;
+let domain = SharedDomain::with_config(Config::default().caching(true));
+let cell = HzrdCell::new_in(0, &domain);
+cell.set(1);
+
+flush_thread_cache();
+```
+*/
+#[cfg(feature = "std")]
+pub fn flush_thread_cache() {
+    drop(HAZARD_POINTERS_CACHE.with(|cell| cell.take()));
+    drop(HAZARD_POINTERS_SET_CACHE.with(|cell| cell.take()));
 }
 
+/// The number of hazard pointers above which [`ReclaimStrategy::Auto`] builds a [`HashSet`]
+const AUTO_HASHED_THRESHOLD: usize = 64;
+
 /// Holds a loaded set of hazard pointers
 struct HzrdPtrs {
     list: Vec<usize>,
+    set: AddrSet,
+    strategy: ReclaimStrategy,
+    #[cfg(feature = "std")]
     caching: bool,
+    warn_long_held: usize,
 }
 
 impl HzrdPtrs {
-    fn load<'t>(hzrd_ptrs: impl Iterator<Item = &'t HzrdPtr>) -> Self {
-        match global_config().caching {
+    fn load<'t>(config: &Config, hzrd_ptrs: impl IntoIterator<Item = &'t HzrdPtr>) -> Self {
+        // Pairs with the fence issued by readers between protecting a value and verifying it:
+        // Either the reader sees that the value has been swapped out, or we see its protection.
+        // The hazard pointers must therefore only be iterated over after this fence.
+        fence(SeqCst);
+        let hzrd_ptrs = hzrd_ptrs.into_iter();
+
+        #[cfg(feature = "std")]
+        let mut loaded = match config.caching {
             false => Self::new(hzrd_ptrs),
             true => Self::cached(hzrd_ptrs),
+        };
+
+        // There are no thread-locals to hold the cache without the standard library
+        #[cfg(not(feature = "std"))]
+        let mut loaded = Self::new(hzrd_ptrs);
+
+        loaded.strategy = match config.reclaim_strategy {
+            ReclaimStrategy::Auto if loaded.list.len() > AUTO_HASHED_THRESHOLD => {
+                ReclaimStrategy::Hashed
+            }
+            ReclaimStrategy::Auto => ReclaimStrategy::Linear,
+            strategy => strategy,
+        };
+        loaded.warn_long_held = config.warn_long_held;
+
+        match loaded.strategy {
+            ReclaimStrategy::Auto | ReclaimStrategy::Linear => {}
+            ReclaimStrategy::Sorted => loaded.list.sort_unstable(),
+            ReclaimStrategy::Hashed => {
+                #[cfg(feature = "std")]
+                if loaded.caching {
+                    loaded.set = HAZARD_POINTERS_SET_CACHE.with(|cell| cell.take());
+                    loaded.set.clear();
+                }
+                loaded.set.extend(loaded.list.iter().copied());
+            }
         }
+
+        loaded
     }
 
     fn new<'t>(hzrd_ptrs: impl Iterator<Item = &'t HzrdPtr>) -> Self {
         Self {
             list: Vec::from_iter(hzrd_ptrs.map(HzrdPtr::get)),
+            set: AddrSet::new(),
+            strategy: ReclaimStrategy::Linear,
+            #[cfg(feature = "std")]
             caching: false,
+            warn_long_held: 0,
         }
     }
 
+    #[cfg(feature = "std")]
     fn cached<'t>(hzrd_ptrs: impl Iterator<Item = &'t HzrdPtr>) -> Self {
         let mut hzrd_ptrs_cache: Vec<usize> = HAZARD_POINTERS_CACHE.with(|cell| cell.take());
         hzrd_ptrs_cache.clear();
@@ -135,15 +338,69 @@ impl HzrdPtrs {
 
         Self {
             list: hzrd_ptrs_cache,
+            set: AddrSet::new(),
+            strategy: ReclaimStrategy::Linear,
             caching: true,
+            warn_long_held: 0,
         }
     }
 
     fn contains(&self, addr: usize) -> bool {
-        self.list.contains(&addr)
+        match self.strategy {
+            ReclaimStrategy::Auto | ReclaimStrategy::Linear => self.list.contains(&addr),
+            ReclaimStrategy::Sorted => self.list.binary_search(&addr).is_ok(),
+            ReclaimStrategy::Hashed => self.set.contains(&addr),
+        }
+    }
+
+    /// Check whether the retired value is protected, warning if it has been protected for too long (only in debug builds)
+    fn protects(&self, retired_ptr: &RetiredPtr) -> bool {
+        let protected = self.contains(retired_ptr.addr());
+
+        // The count only reaches the limit once, and so each value is warned about at most once
+        #[cfg(all(debug_assertions, feature = "std"))]
+        if protected
+            && self.warn_long_held != 0
+            && retired_ptr.protected_through_reclaim() == self.warn_long_held
+        {
+            warn_long_held(retired_ptr.addr(), self.warn_long_held);
+        }
+
+        #[cfg(not(all(debug_assertions, feature = "std")))]
+        let _ = self.warn_long_held;
+
+        protected
+    }
+
+    /// The number of retired values which can be checked within the given number of comparisons
+    fn checks_within(&self, max_checks: usize) -> usize {
+        // Each check costs at least one comparison, even if there are no hazard pointers
+        let cost = match self.strategy {
+            ReclaimStrategy::Auto | ReclaimStrategy::Linear => self.list.len(),
+            ReclaimStrategy::Sorted => self.list.len().checked_ilog2().unwrap_or(0) as usize + 1,
+            ReclaimStrategy::Hashed => 1,
+        };
+        max_checks / cost.max(1)
     }
 }
 
+/// Number of warnings printed by [`warn_long_held`], for testing that each value is only warned about once
+#[cfg(all(test, debug_assertions, feature = "std"))]
+static LONG_HELD_WARNINGS: core::sync::atomic::AtomicUsize =
+    core::sync::atomic::AtomicUsize::new(0);
+
+/// Warn that the retired value at the given address has been protected through the given number of reclamations
+#[cold]
+#[cfg(all(debug_assertions, feature = "std"))]
+fn warn_long_held(addr: usize, reclaims: usize) {
+    #[cfg(test)]
+    LONG_HELD_WARNINGS.fetch_add(1, Relaxed);
+
+    eprintln!(
+        "hzrd: retired value at {addr:#X} has been protected through {reclaims} reclamations, a `ReadHandle` may be held for too long",
+    );
+}
+
 /**
 If the hazard pointers were loaded using the cache we'll return the cache
 
@@ -151,24 +408,142 @@ If the cache is loaded twice in overlap then only the first will get a cache-hit
 The second load will then need to allocate all memory needed.
 The cache will be overwritten by the last to access it.
 */
+#[cfg(feature = "std")]
 impl Drop for HzrdPtrs {
     fn drop(&mut self) {
         if self.caching {
-            let list = std::mem::take(&mut self.list);
+            let list = core::mem::take(&mut self.list);
             HAZARD_POINTERS_CACHE.with(|cell| cell.set(list));
+
+            if self.strategy == ReclaimStrategy::Hashed {
+                let set = core::mem::take(&mut self.set);
+                HAZARD_POINTERS_SET_CACHE.with(|cell| cell.set(set));
+            }
+        }
+    }
+}
+
+/// Collect the stats of a domain with the given hazard pointers
+fn stats<'t>(
+    hzrd_ptrs: impl IntoIterator<Item = &'t HzrdPtr>,
+    retired_pending: usize,
+) -> DomainStats {
+    let mut stats = DomainStats {
+        retired_pending,
+        ..DomainStats::default()
+    };
+
+    for hzrd_ptr in hzrd_ptrs {
+        stats.hzrd_ptrs_total += 1;
+        if hzrd_ptr.get() != 0 {
+            stats.hzrd_ptrs_active += 1;
+        }
+    }
+
+    stats
+}
+
+/// Count the hazard pointers protecting the given address
+fn protection_count<'t>(hzrd_ptrs: impl IntoIterator<Item = &'t HzrdPtr>, addr: usize) -> usize {
+    hzrd_ptrs
+        .into_iter()
+        .filter(|hzrd_ptr| hzrd_ptr.protected_addr() == Some(addr))
+        .count()
+}
+
+// -------------------------------------
+
+/// A unique id for a domain, which is assigned on first use
+///
+/// The id is only used for diagnostics, and so it always uses the atomics from the standard library
+#[derive(Debug)]
+struct DomainId(core::sync::atomic::AtomicUsize);
+
+impl DomainId {
+    const fn new() -> Self {
+        Self(core::sync::atomic::AtomicUsize::new(0))
+    }
+
+    fn get(&self) -> usize {
+        static NEXT_ID: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(1);
+
+        let id = self.0.load(Relaxed);
+        if id != 0 {
+            return id;
+        }
+
+        let new_id = NEXT_ID.fetch_add(1, Relaxed);
+        match self.0.compare_exchange(0, new_id, Relaxed, Relaxed) {
+            Ok(_) => new_id,
+            Err(id) => id,
         }
     }
 }
 
 // -------------------------------------
 
+#[cfg(not(any(
+    loom,
+    all(
+        feature = "single-thread",
+        target_arch = "wasm32",
+        not(target_feature = "atomics")
+    )
+)))]
 static GLOBAL_DOMAIN: SharedDomain = SharedDomain::new();
 
+// Loom requires statics to be reset between each execution of a model
+#[cfg(loom)]
+loom::lazy_static! {
+    static ref GLOBAL_DOMAIN: SharedDomain = SharedDomain::new();
+}
+
+#[cfg(all(
+    feature = "single-thread",
+    target_arch = "wasm32",
+    not(target_feature = "atomics")
+))]
+static GLOBAL_DOMAIN: single_thread::StaticDomain = single_thread::StaticDomain::new();
+
+/// Single-threaded backing for the [`GlobalDomain`], used on targets without threads
+#[cfg(all(
+    feature = "single-thread",
+    target_arch = "wasm32",
+    not(target_feature = "atomics")
+))]
+mod single_thread {
+    use core::ops::Deref;
+
+    use super::LocalDomain;
+
+    #[derive(Debug)]
+    pub struct StaticDomain(LocalDomain);
+
+    impl StaticDomain {
+        pub const fn new() -> Self {
+            Self(LocalDomain::new())
+        }
+    }
+
+    impl Deref for StaticDomain {
+        type Target = LocalDomain;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    // SAFETY: This is only used on targets without support for threads
+    unsafe impl Sync for StaticDomain {}
+}
+
 /**
 A globally shared, multithreaded domain
 
 This is the default domain used by `HzrdCell`, and is the recommended domain for most applications. It's based on a globally shared, static variable, and so there is no "constructor" for this domain. The [`GlobalDomain`] struct is a Zero Sized Type (ZST) that acts simply as an accessor to this globally shared variable.
 
+# Single-threaded targets
+On `wasm32` targets without support for atomics there can only ever be a single thread. For these targets the `single-thread` feature can be enabled, which makes the global domain use a non-atomic backing, similar to [`LocalDomain`]. The API is unchanged, and the feature has no effect on any other targets.
+
 # Example
 ```
 use hzrd::domains::GlobalDomain;
@@ -231,10 +606,46 @@ unsafe impl Domain for GlobalDomain {
     fn reclaim(&self) -> usize {
         GLOBAL_DOMAIN.reclaim()
     }
+
+    fn reclaim_all(&self) -> usize {
+        GLOBAL_DOMAIN.reclaim_all()
+    }
+
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        GLOBAL_DOMAIN.reclaim_if_over(threshold)
+    }
+
+    fn try_reclaim_with_budget(&self, max_checks: usize) -> usize {
+        GLOBAL_DOMAIN.try_reclaim_with_budget(max_checks)
+    }
+
+    fn retired_count(&self) -> usize {
+        GLOBAL_DOMAIN.retired_count()
+    }
+
+    fn hzrd_ptr_count(&self) -> usize {
+        GLOBAL_DOMAIN.hzrd_ptr_count()
+    }
+
+    fn stats(&self) -> DomainStats {
+        GLOBAL_DOMAIN.stats()
+    }
+
+    fn domain_id(&self) -> usize {
+        GLOBAL_DOMAIN.domain_id()
+    }
+
+    fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+        GLOBAL_DOMAIN.report_long_held(older_than)
+    }
+
+    fn protection_count(&self, addr: usize) -> usize {
+        GLOBAL_DOMAIN.protection_count(addr)
+    }
 }
 
-impl std::fmt::Debug for GlobalDomain {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for GlobalDomain {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         GLOBAL_DOMAIN.fmt(f)
     }
 }
@@ -283,11 +694,16 @@ let cell_2 = HzrdCell::new_in(false, Arc::clone(&custom_domain));
 # assert_eq!(cell_1.get(), 0);
 # assert_eq!(cell_2.get(), false);
 ```
+
+# Dropping
+When a [`SharedDomain`] is dropped all of its retired values are freed, without checking the hazard pointers. This is sound since nothing can be read from the domain once it's gone, and it also means values are not leaked if hazard pointers are still marked as protecting them, such as when a [`ReadHandle`](`crate::core::ReadHandle`) has been forgotten with [`std::mem::forget`].
 */
-#[derive(Debug)]
 pub struct SharedDomain {
     hzrd_ptrs: SharedStack<HzrdPtr>,
     retired_ptrs: SharedStack<RetiredPtr>,
+    retired_count: AtomicUsize,
+    id: DomainId,
+    config: Option<Config>,
 }
 
 impl Default for SharedDomain {
@@ -296,21 +712,205 @@ impl Default for SharedDomain {
     }
 }
 
+impl core::fmt::Debug for SharedDomain {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The retired values can be reclaimed while we format, and so only their count is shown
+        f.debug_struct("SharedDomain")
+            .field("hzrd_ptrs", &self.hzrd_ptrs)
+            .field("retired_count", &self.retired_count)
+            .field("id", &self.id)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
 impl SharedDomain {
+    const_fn! {
+        /**
+        Construct a new, clean shared domain
+
+        # Example
+        ```
+        # use hzrd::domains::SharedDomain;
+        let domain = SharedDomain::new();
+        ```
+        */
+        pub fn new() -> Self {
+            Self {
+                hzrd_ptrs: SharedStack::new(),
+                retired_ptrs: SharedStack::new(),
+                retired_count: AtomicUsize::new(0),
+                id: DomainId::new(),
+                config: None,
+            }
+        }
+    }
+
     /**
-    Construct a new, clean shared domain
+    Construct a new, clean shared domain with its own config
+
+    The given config is used instead of [`GLOBAL_CONFIG`] for this domain. Any hazard pointers to [`preallocate`](`Config::preallocate`) are allocated right away.
 
     # Example
     ```
-    # use hzrd::domains::SharedDomain;
-    let domain = SharedDomain::new();
+    use hzrd::core::Domain;
+    use hzrd::domains::{Config, SharedDomain};
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::with_config(Config::default().bulk_size(2));
+    let cell = HzrdCell::new_in(0, &domain);
+
+    cell.just_set(1); // Current garbage: { 0 }
+    assert_eq!(domain.reclaim(), 0);
+
+    cell.just_set(2); // Current garbage: { 0, 1 }
+    assert_eq!(domain.reclaim(), 2);
     ```
     */
-    pub const fn new() -> Self {
-        Self {
+    pub fn with_config(config: Config) -> Self {
+        let domain = Self {
             hzrd_ptrs: SharedStack::new(),
             retired_ptrs: SharedStack::new(),
+            retired_count: AtomicUsize::new(0),
+            id: DomainId::new(),
+            config: Some(config),
+        };
+
+        for _ in 0..config.preallocate {
+            let hzrd_ptr = domain
+                .hzrd_ptrs
+                .push_get(HzrdPtr::new_in_domain(domain.domain_id()));
+            // SAFETY: The hazard pointer has not been handed out yet
+            unsafe { hzrd_ptr.release() };
+        }
+
+        domain
+    }
+
+    /**
+    Construct one cell per value, all sharing this domain
+
+    This is a shorthand for calling [`HzrdCell::new_in`] with a clone of the [`Arc`] for every value.
+
+    # Example
+    ```
+    use std::sync::Arc;
+
+    use hzrd::domains::SharedDomain;
+
+    let domain = Arc::new(SharedDomain::new());
+    let cells = domain.cells([1, 2, 3]);
+
+    cells[1].set(4);
+    assert_eq!(cells.iter().map(|cell| cell.get()).sum::<i32>(), 8);
+    assert_eq!(Arc::strong_count(&domain), 4);
+    ```
+    */
+    pub fn cells<T, I>(self: &Arc<Self>, values: I) -> Vec<HzrdCell<T, Arc<Self>>>
+    where
+        T: 'static,
+        I: IntoIterator<Item = T>,
+    {
+        values
+            .into_iter()
+            .map(|value| HzrdCell::new_in(value, Arc::clone(self)))
+            .collect()
+    }
+
+    /**
+    Get the addresses currently protected by the hazard pointers of this domain
+
+    Only hazard pointers which are actively protecting a value are included, free or idle hazard pointers are skipped. This is meant for diagnostics, such as correlating protected values with retired values which can't be reclaimed yet. The addresses are only a snapshot, and may be stale by the time they're inspected.
+
+    # Example
+    ```
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    assert!(domain.protected_addresses().is_empty());
+
+    let handle = cell.read();
+    assert_eq!(domain.protected_addresses(), [&*handle as *const i32 as usize]);
+    ```
+    */
+    pub fn protected_addresses(&self) -> Vec<usize> {
+        self.hzrd_ptrs
+            .iter()
+            .filter_map(HzrdPtr::protected_addr)
+            .collect()
+    }
+
+    /**
+    Move all the retired values of another domain into this domain
+
+    This is useful for handing the garbage of a short-lived domain over to a long-lived one, instead of reclaiming it when the short-lived domain is dropped. The retired values are moved over in one go, without being checked against any hazard pointers, and are reclaimed by this domain later on.
+
+    The other domain is taken by value, and so nothing can be borrowing it: There are no cells, readers or handles left in the domain, and so none of its hazard pointers can be protecting a value which is still reachable. The hazard pointers are therefore not moved over, but are freed along with the other domain.
+
+    # Example
+    ```
+    use hzrd::core::Domain;
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let domain = SharedDomain::new();
+
+    let task_domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &task_domain);
+    let handle = cell.read();
+    cell.set(1);
+    drop(handle);
+    drop(cell);
+    assert_eq!(task_domain.retired_count(), 1);
+
+    domain.merge(task_domain);
+    assert_eq!(domain.retired_count(), 1);
+    assert_eq!(domain.reclaim(), 1);
+    ```
+    */
+    pub fn merge(&self, other: SharedDomain) {
+        let mut other = other;
+        let retired_ptrs = core::mem::take(&mut other.retired_ptrs);
+
+        // The count is incremented first, such that it never drops below zero
+        let count = other.retired_count.swap(0, SeqCst);
+        self.retired_count.fetch_add(count, SeqCst);
+        self.retired_ptrs.push_stack(retired_ptrs);
+    }
+
+    /// The config of this domain, falling back to the global config if none was given
+    fn config(&self) -> &Config {
+        match &self.config {
+            Some(config) => config,
+            None => global_config(),
+        }
+    }
+
+    /// Reclaim all "reclaimable" memory, handing each reclaimed pointer to the given function
+    ///
+    /// Nothing is reclaimed if there are fewer than `bulk_size` retired pointers
+    fn reclaim_with(&self, bulk_size: usize, reclaimed: impl FnMut(RetiredPtr)) -> usize {
+        // Check if it's too small to reclaim
+        if self.retired_count() < bulk_size {
+            return 0;
         }
+
+        // The hazard pointers must be loaded after the retired pointers are taken off the stack,
+        // which happens before the predicate is first called
+        let mut hzrd_ptrs = None;
+        // SAFETY: The retired pointers are never iterated over or popped, not even when formatted
+        let removed = unsafe {
+            self.retired_ptrs.drain_filter(|p| {
+                let hzrd_ptrs =
+                    hzrd_ptrs.get_or_insert_with(|| HzrdPtrs::load(self.config(), &self.hzrd_ptrs));
+                !hzrd_ptrs.protects(p)
+            })
+        };
+        let n_reclaimed = removed.into_iter().map(reclaimed).count();
+        self.retired_count.fetch_sub(n_reclaimed, SeqCst);
+        n_reclaimed
     }
 
     #[cfg(test)]
@@ -320,6 +920,7 @@ impl SharedDomain {
 
     #[cfg(test)]
     pub(crate) fn number_of_retired_ptrs(&self) -> usize {
+        // SAFETY: The retired pointers are never iterated over or popped, not even when formatted
         let tooketh = unsafe { self.retired_ptrs.take() };
         let size = tooketh.iter().count();
         self.retired_ptrs.push_stack(tooketh);
@@ -331,69 +932,273 @@ unsafe impl Domain for SharedDomain {
     fn hzrd_ptr(&self) -> &HzrdPtr {
         match self.hzrd_ptrs.iter().find_map(|node| node.try_acquire()) {
             Some(hzrd_ptr) => hzrd_ptr,
-            None => self.hzrd_ptrs.push_get(HzrdPtr::new()),
+            None => {
+                let hzrd_ptr = HzrdPtr::new_in_domain(self.domain_id());
+                self.hzrd_ptrs.push_get(hzrd_ptr)
+            }
         }
     }
 
     fn just_retire(&self, ret_ptr: RetiredPtr) {
+        // The count is incremented first, such that it never drops below zero
+        self.retired_count.fetch_add(1, SeqCst);
         self.retired_ptrs.push(ret_ptr);
     }
 
-    fn reclaim(&self) -> usize {
-        let retired_ptrs = unsafe { self.retired_ptrs.take() };
-        let prev_size = retired_ptrs.iter().count();
+    fn retired_count(&self) -> usize {
+        self.retired_count.load(SeqCst)
+    }
 
-        // Check if it's too small to reclaim
-        if prev_size < global_config().bulk_size {
-            return 0;
-        }
+    fn hzrd_ptr_count(&self) -> usize {
+        self.hzrd_ptrs.iter().count()
+    }
 
-        let hzrd_ptrs = HzrdPtrs::load(self.hzrd_ptrs.iter());
-        let remaining: SharedStack<RetiredPtr> = retired_ptrs
-            .into_iter()
-            .filter(|retired_ptr| hzrd_ptrs.contains(retired_ptr.addr()))
-            .collect();
+    fn stats(&self) -> DomainStats {
+        stats(&self.hzrd_ptrs, self.retired_count())
+    }
 
-        let new_size = remaining.iter().count();
-        self.retired_ptrs.push_stack(remaining);
-        assert!(prev_size >= new_size);
-        prev_size - new_size
+    fn protection_count(&self, addr: usize) -> usize {
+        protection_count(&self.hzrd_ptrs, addr)
     }
-}
 
-// -------------------------------------
+    fn domain_id(&self) -> usize {
+        self.id.get()
+    }
 
-use shared_cell::SharedCell;
+    fn reclaim(&self) -> usize {
+        self.reclaim_with(self.config().bulk_size, drop)
+    }
 
-mod shared_cell {
-    pub(crate) struct SharedCell<T>(T);
+    fn reclaim_all(&self) -> usize {
+        self.reclaim_with(1, drop)
+    }
 
-    impl<T> SharedCell<T> {
-        pub(crate) fn new(value: T) -> Self {
-            Self(value)
-        }
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        self.reclaim_with(threshold.saturating_add(1), drop)
+    }
 
-        pub(crate) fn get(&self) -> &T {
-            &self.0
-        }
+    fn try_reclaim_with_budget(&self, max_checks: usize) -> usize {
+        // The hazard pointers must be loaded after the retired pointers are taken off the stack,
+        // which happens before the predicate is first called
+        let mut hzrd_ptrs = None;
+        let mut budget = 0;
+        let mut reclaimed = 0;
+
+        // Only the protected pointers are kept, the unchecked ones are removed to be pushed back
+        // SAFETY: The retired pointers are never iterated over or popped, not even when formatted
+        let removed = unsafe {
+            self.retired_ptrs.drain_filter(|p| {
+                let hzrd_ptrs = hzrd_ptrs.get_or_insert_with(|| {
+                    let hzrd_ptrs = HzrdPtrs::load(self.config(), &self.hzrd_ptrs);
+                    budget = hzrd_ptrs.checks_within(max_checks);
+                    hzrd_ptrs
+                });
+
+                // Stop checking once the budget is spent
+                if budget == 0 {
+                    return true;
+                }
+
+                budget -= 1;
+                let unprotected = !hzrd_ptrs.protects(p);
+                reclaimed += usize::from(unprotected);
+                unprotected
+            })
+        };
+
+        // The removed pointers keep their order, and so the unprotected ones come first
+        let mut skipped = 0;
+        // SAFETY: We own the stack of removed pointers
+        let unchecked = unsafe {
+            removed.drain_filter(|_| {
+                skipped += 1;
+                skipped > reclaimed
+            })
+        };
+
+        // The unchecked pointers are put on top, such that they are checked first next time
+        drop(removed);
+        self.retired_ptrs.push_stack(unchecked);
+        self.retired_count.fetch_sub(reclaimed, SeqCst);
+        reclaimed
+    }
+
+    fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+        self.hzrd_ptrs
+            .iter()
+            .filter_map(|hzrd_ptr| hzrd_ptr.leak_info(older_than))
+            .collect()
     }
 }
 
+// -------------------------------------
+
 /**
-Local, singlethreaded domain
+A multithreaded, shared domain which hands reclaimed values back to the caller
 
-The main use case for this is when only a single thread needs to be able to write to a cell. Since the `Domain` is not `Sync` the `HzrdCell` constructed with it won't be either, as this requires both the value held and the domain to be thread-safe. However, `HzrdReader` holds no access to the domain, only a reference to the value. It is therefore `Send` if and only if the value held is both `Send` and `Sync`. Using this we can create a single-writer, multiple-readers construct.
+This domain works like a [`SharedDomain`], but values of type `T` can be recovered when they are reclaimed, rather than being dropped. This is useful for recycling allocations, such as buffers, which are expensive to create. Use [`reclaim_into_pool`](`RecyclingDomain::reclaim_into_pool`) to collect the reclaimed values, and [`HzrdCell::just_set_boxed`](`crate::HzrdCell::just_set_boxed`) to put them back into use. Any values retired in the domain which are not of type `T` are simply dropped when reclaimed.
+
+Note that [`HzrdCell::set`](`crate::HzrdCell::set`) and [`HzrdCell::set_boxed`](`crate::HzrdCell::set_boxed`) will try to reclaim memory the regular way, which drops the reclaimed values. Use [`HzrdCell::just_set`](`crate::HzrdCell::just_set`) or [`HzrdCell::just_set_boxed`](`crate::HzrdCell::just_set_boxed`) to keep the values around until they are recycled.
 
 # Example
 ```
-use std::sync::Barrier;
-
-use hzrd::domains::LocalDomain;
+use hzrd::domains::RecyclingDomain;
 use hzrd::HzrdCell;
 
-const N_THREADS: usize = 10;
+let domain = RecyclingDomain::<Vec<u8>>::new();
+let cell = HzrdCell::new_in(vec![0; 1024], &domain);
 
-let cell = HzrdCell::new_in(0, LocalDomain::new());
+let mut pool = Vec::new();
+cell.just_set_boxed(Box::new(vec![1; 1024]));
+domain.reclaim_into_pool(&mut pool);
+
+// The old buffer can now be reused
+let mut buffer = pool.pop().unwrap();
+buffer.fill(2);
+cell.just_set_boxed(buffer);
+# assert_eq!(cell.read()[0], 2);
+```
+*/
+pub struct RecyclingDomain<T> {
+    domain: SharedDomain,
+    _marker: PhantomData<fn(Box<T>)>,
+}
+
+impl<T> Default for RecyclingDomain<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RecyclingDomain<T> {
+    const_fn! {
+        /**
+        Construct a new, clean recycling domain
+
+        # Example
+        ```
+        # use hzrd::domains::RecyclingDomain;
+        let domain = RecyclingDomain::<Vec<u8>>::new();
+        ```
+        */
+        pub fn new() -> Self {
+            Self {
+                domain: SharedDomain::new(),
+                _marker: PhantomData,
+            }
+        }
+    }
+}
+
+impl<T: 'static> RecyclingDomain<T> {
+    /**
+    Reclaim all "reclaimable" memory, moving values of type `T` into the given pool
+
+    The method returns the number of reclaimed objects, which includes any objects not of type `T`.
+    */
+    pub fn reclaim_into_pool(&self, pool: &mut Vec<Box<T>>) -> usize {
+        self.domain
+            .reclaim_with(self.domain.config().bulk_size, |retired_ptr| {
+                // SAFETY: Only pointers not protected by any hazard pointers are reclaimed
+                if let Ok(boxed) = unsafe { retired_ptr.downcast() } {
+                    pool.push(boxed);
+                }
+            })
+    }
+}
+
+unsafe impl<T> Domain for RecyclingDomain<T> {
+    fn hzrd_ptr(&self) -> &HzrdPtr {
+        self.domain.hzrd_ptr()
+    }
+
+    fn just_retire(&self, ret_ptr: RetiredPtr) {
+        self.domain.just_retire(ret_ptr);
+    }
+
+    fn reclaim(&self) -> usize {
+        self.domain.reclaim()
+    }
+
+    fn reclaim_all(&self) -> usize {
+        self.domain.reclaim_all()
+    }
+
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        self.domain.reclaim_if_over(threshold)
+    }
+
+    fn try_reclaim_with_budget(&self, max_checks: usize) -> usize {
+        self.domain.try_reclaim_with_budget(max_checks)
+    }
+
+    fn retired_count(&self) -> usize {
+        self.domain.retired_count()
+    }
+
+    fn hzrd_ptr_count(&self) -> usize {
+        self.domain.hzrd_ptr_count()
+    }
+
+    fn stats(&self) -> DomainStats {
+        self.domain.stats()
+    }
+
+    fn domain_id(&self) -> usize {
+        self.domain.domain_id()
+    }
+
+    fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+        self.domain.report_long_held(older_than)
+    }
+
+    fn protection_count(&self, addr: usize) -> usize {
+        self.domain.protection_count(addr)
+    }
+}
+
+impl<T> core::fmt::Debug for RecyclingDomain<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RecyclingDomain")
+            .field("hzrd_ptrs", &self.domain.hzrd_ptrs)
+            .field("retired_count", &self.domain.retired_count)
+            .finish()
+    }
+}
+
+// -------------------------------------
+
+use shared_cell::SharedCell;
+
+mod shared_cell {
+    pub(crate) struct SharedCell<T>(T);
+
+    impl<T> SharedCell<T> {
+        pub(crate) fn new(value: T) -> Self {
+            Self(value)
+        }
+
+        pub(crate) fn get(&self) -> &T {
+            &self.0
+        }
+    }
+}
+
+/**
+Local, singlethreaded domain
+
+The main use case for this is when only a single thread needs to be able to write to a cell. Since the `Domain` is not `Sync` the `HzrdCell` constructed with it won't be either, as this requires both the value held and the domain to be thread-safe. However, `HzrdReader` only accesses the domain in methods requiring the domain to be `Sync`, and otherwise only holds a reference to the value. It is therefore `Send` if and only if the value held is both `Send` and `Sync`. Using this we can create a single-writer, multiple-readers construct.
+
+# Example
+```
+use std::sync::Barrier;
+
+use hzrd::domains::LocalDomain;
+use hzrd::HzrdCell;
+
+const N_THREADS: usize = 10;
+
+let cell = HzrdCell::new_in(0, LocalDomain::new());
 let barrier = Barrier::new(N_THREADS + 1);
 
 // We use scoped threads to avoid requirements for 'static lifetimes
@@ -405,115 +1210,857 @@ std::thread::scope(|s| {
         // Borrow this, so as to not move it into the thread
         let barrier = &barrier;
 
-        // We now send the reader to the spawned thread
-        s.spawn(move || {
-            // Wait for everyone to be ready
-            barrier.wait();
+        // We now send the reader to the spawned thread
+        s.spawn(move || {
+            // Wait for everyone to be ready
+            barrier.wait();
+
+            // All threads read at the same time!
+            println!("[{i}]: {}", reader.get());
+        });
+    }
+
+    // Wait for all the threads to be ready
+    barrier.wait();
+
+    // Then start counting
+    for num in 1..5 {
+        // Don't perform garbage collection
+        cell.just_set(num);
+    }
+
+    // We're done, but no need to clean up...
+});
+
+// The cell is dropped, all garbage is cleaned up
+drop(cell);
+```
+*/
+#[derive(Debug)]
+pub struct LocalDomain {
+    // Important to only allow shared references to the HzrdPtr's
+    hzrd_ptrs: UnsafeCell<LinkedList<SharedCell<HzrdPtr>>>,
+    retired_ptrs: UnsafeCell<Vec<RetiredPtr>>,
+    id: DomainId,
+    config: Option<Config>,
+}
+
+impl Default for LocalDomain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalDomain {
+    /**
+    Construct a new, clean local domain
+
+    # Example
+    ```
+    # use hzrd::domains::LocalDomain;
+    let domain = LocalDomain::new();
+    ```
+    */
+    pub const fn new() -> Self {
+        Self {
+            hzrd_ptrs: UnsafeCell::new(LinkedList::new()),
+            retired_ptrs: UnsafeCell::new(Vec::new()),
+            id: DomainId::new(),
+            config: None,
+        }
+    }
+
+    /**
+    Construct a new, clean local domain with its own config
+
+    The given config is used instead of [`GLOBAL_CONFIG`] for this domain. Any hazard pointers to [`preallocate`](`Config::preallocate`) are allocated right away.
+
+    # Example
+    ```
+    # use hzrd::domains::{Config, LocalDomain};
+    let domain = LocalDomain::with_config(Config::default().caching(true));
+    ```
+    */
+    pub fn with_config(config: Config) -> Self {
+        let mut domain = Self {
+            hzrd_ptrs: UnsafeCell::new(LinkedList::new()),
+            retired_ptrs: UnsafeCell::new(Vec::new()),
+            id: DomainId::new(),
+            config: Some(config),
+        };
+
+        let domain_id = domain.domain_id();
+        let hzrd_ptrs = domain.hzrd_ptrs.get_mut();
+        for _ in 0..config.preallocate {
+            let hzrd_ptr = HzrdPtr::new_in_domain(domain_id);
+            // SAFETY: The hazard pointer has not been handed out yet
+            unsafe { hzrd_ptr.release() };
+            hzrd_ptrs.push_back(SharedCell::new(hzrd_ptr));
+        }
+
+        domain
+    }
+
+    /**
+    Construct a reusable reader, which can read any cell in this domain
+
+    The reader acquires a single hazard pointer up front, which is reused for every read. This avoids searching the list of hazard pointers on each read, and a single reader can be shared by all the cells of the domain. See [`LocalReader`] for more details.
+
+    # Example
+    ```
+    use hzrd::domains::LocalDomain;
+    use hzrd::HzrdCell;
+
+    let domain = LocalDomain::new();
+    let cell_1 = HzrdCell::new_in(1, &domain);
+    let cell_2 = HzrdCell::new_in(2, &domain);
+
+    let mut reader = domain.reader_handle();
+    let a = *reader.read(&cell_1);
+    let b = *reader.read(&cell_2);
+    assert_eq!(a + b, 3);
+    ```
+    */
+    pub fn reader_handle(&self) -> LocalReader<'_> {
+        LocalReader {
+            domain: self,
+            hzrd_ptr: self.hzrd_ptr(),
+        }
+    }
+
+    /// The config of this domain, falling back to the global config if none was given
+    fn config(&self) -> &Config {
+        match &self.config {
+            Some(config) => config,
+            None => global_config(),
+        }
+    }
+
+    /// Reclaim all "reclaimable" memory, if there are at least `bulk_size` retired pointers
+    fn reclaim_over(&self, bulk_size: usize) -> usize {
+        let retired_ptrs = unsafe { &mut *self.retired_ptrs.get() };
+        let hzrd_ptrs = unsafe { &mut *self.hzrd_ptrs.get() };
+
+        let prev_size = retired_ptrs.len();
+
+        // Check if it's too small to reclaim
+        if prev_size < bulk_size {
+            return 0;
+        }
+
+        let hzrd_ptrs = HzrdPtrs::load(self.config(), hzrd_ptrs.iter().map(SharedCell::get));
+        retired_ptrs.retain(|p| hzrd_ptrs.protects(p));
+        prev_size - retired_ptrs.len()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn number_of_hzrd_ptrs(&self) -> usize {
+        unsafe { (*self.hzrd_ptrs.get()).len() }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn number_of_retired_ptrs(&self) -> usize {
+        unsafe { (*self.retired_ptrs.get()).len() }
+    }
+}
+
+unsafe impl Domain for LocalDomain {
+    fn hzrd_ptr(&self) -> &HzrdPtr {
+        {
+            let hzrd_ptrs = unsafe { &*self.hzrd_ptrs.get() };
+
+            if let Some(hzrd_ptr) = hzrd_ptrs.iter().find_map(|node| node.get().try_acquire()) {
+                return hzrd_ptr;
+            }
+        }
+
+        let hzrd_ptrs = unsafe { &mut *self.hzrd_ptrs.get() };
+        let hzrd_ptr = HzrdPtr::new_in_domain(self.domain_id());
+        hzrd_ptrs.push_back(SharedCell::new(hzrd_ptr));
+        unsafe { hzrd_ptrs.back().unwrap_unchecked().get() }
+    }
+
+    fn just_retire(&self, ret_ptr: RetiredPtr) {
+        let retired_ptrs = unsafe { &mut *self.retired_ptrs.get() };
+        retired_ptrs.push(ret_ptr);
+    }
+
+    fn retired_count(&self) -> usize {
+        unsafe { (*self.retired_ptrs.get()).len() }
+    }
+
+    fn hzrd_ptr_count(&self) -> usize {
+        unsafe { (*self.hzrd_ptrs.get()).len() }
+    }
+
+    fn stats(&self) -> DomainStats {
+        let hzrd_ptrs = unsafe { &*self.hzrd_ptrs.get() };
+        stats(hzrd_ptrs.iter().map(SharedCell::get), self.retired_count())
+    }
+
+    fn protection_count(&self, addr: usize) -> usize {
+        let hzrd_ptrs = unsafe { &*self.hzrd_ptrs.get() };
+        protection_count(hzrd_ptrs.iter().map(SharedCell::get), addr)
+    }
+
+    fn domain_id(&self) -> usize {
+        self.id.get()
+    }
+
+    fn reclaim(&self) -> usize {
+        self.reclaim_over(self.config().bulk_size)
+    }
+
+    fn reclaim_all(&self) -> usize {
+        self.reclaim_over(1)
+    }
+
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        self.reclaim_over(threshold.saturating_add(1))
+    }
+
+    fn try_reclaim_with_budget(&self, max_checks: usize) -> usize {
+        let retired_ptrs = unsafe { &mut *self.retired_ptrs.get() };
+        let hzrd_ptrs = unsafe { &*self.hzrd_ptrs.get() };
+        let hzrd_ptrs = HzrdPtrs::load(self.config(), hzrd_ptrs.iter().map(SharedCell::get));
+
+        let checked = hzrd_ptrs.checks_within(max_checks).min(retired_ptrs.len());
+        let unchecked = retired_ptrs.split_off(checked);
+        let mut protected = core::mem::replace(retired_ptrs, unchecked);
+        protected.retain(|p| hzrd_ptrs.protects(p));
+
+        // The protected pointers are put last, such that the unchecked ones are checked first next time
+        let reclaimed = checked - protected.len();
+        retired_ptrs.append(&mut protected);
+        reclaimed
+    }
+
+    fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+        let hzrd_ptrs = unsafe { &*self.hzrd_ptrs.get() };
+        hzrd_ptrs
+            .iter()
+            .filter_map(|hzrd_ptr| hzrd_ptr.get().leak_info(older_than))
+            .collect()
+    }
+}
+
+/**
+Reusable reader for cells in a [`LocalDomain`], see [`LocalDomain::reader_handle`]
+
+The reader holds a hazard pointer from the domain, which is used for every read, and is released when the reader is dropped. Only a single value can be read at a time, which is enforced by [`read`](LocalReader::read) taking `&mut self`.
+
+The reader borrows the domain, which is not `Sync`, and so the reader can not be sent to another thread. This means it can only be used on the thread which owns the domain, which is what makes it safe to hold on to the hazard pointer without any synchronization with the domain. Use [`HzrdCell::reader`] to construct readers which can be sent to other threads.
+*/
+pub struct LocalReader<'domain> {
+    domain: &'domain LocalDomain,
+    hzrd_ptr: &'domain HzrdPtr,
+}
+
+impl LocalReader<'_> {
+    /**
+    Read the value of the given cell, which must belong to the domain of the reader
+
+    The domain of the cell is checked through its [`domain_id`](Domain::domain_id), and so the cell may hold the domain in any form which dereferences to it, such as `&LocalDomain` or `Rc<LocalDomain>`.
+
+    # Panics
+    Panics if the cell belongs to another domain.
+
+    # Example
+    ```
+    # use hzrd::domains::LocalDomain;
+    # use hzrd::HzrdCell;
+    let domain = LocalDomain::new();
+    let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+
+    let mut reader = domain.reader_handle();
+    for _ in 0..10 {
+        assert_eq!(*reader.read(&cell), "Hello");
+    }
+    ```
+    */
+    pub fn read<'r, T, D: Domain>(&'r mut self, cell: &'r HzrdCell<T, D>) -> ReadHandle<'r, T> {
+        // A domain may only report the id of the reader's domain if it forwards to it
+        assert_eq!(
+            cell.domain.domain_id(),
+            self.domain.domain_id(),
+            "The cell does not belong to the domain of the reader"
+        );
+
+        // SAFETY: The hazard pointer belongs to the domain of the cell, and is owned by the reader
+        unsafe { ReadHandle::read_unchecked(&cell.value, self.hzrd_ptr, Action::Reset) }
+    }
+}
+
+impl Drop for LocalReader<'_> {
+    fn drop(&mut self) {
+        // SAFETY: We are the current owner of the hazard pointer
+        unsafe { self.hzrd_ptr.release() };
+    }
+}
+
+impl core::fmt::Debug for LocalReader<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LocalReader").finish_non_exhaustive()
+    }
+}
+
+// -------------------------------------
+
+/// What a [`FixedDomain`] does when asked for a hazard pointer while all of them are in use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OnExhausted {
+    /// Panic (default)
+    #[default]
+    Panic,
+    /// Wait for a hazard pointer to be released
+    Wait,
+}
+
+/**
+Multithreaded domain with a fixed number of hazard pointers
+
+All `N` hazard pointers are allocated up front, as part of the domain itself, and the domain never allocates new ones. This makes the domain suitable for real-time or embedded applications, where the number of readers is known and allocating on reads is unacceptable. Retiring values still requires allocation.
+
+If all the hazard pointers are in use then [`hzrd_ptr`](`Domain::hzrd_ptr`) either panics or waits for one to be released, see [`OnExhausted`]. Use [`try_hzrd_ptr`](`Domain::try_hzrd_ptr`), or [`HzrdCell::try_read`](`crate::HzrdCell::try_read`), to handle this gracefully.
+
+# Example
+```
+use hzrd::domains::{FixedDomain, OnExhausted};
+use hzrd::HzrdCell;
+
+let domain = FixedDomain::<2>::new().on_exhausted(OnExhausted::Panic);
+let cell = HzrdCell::new_in(0, &domain);
+
+let handle_1 = cell.read();
+let handle_2 = cell.read();
+
+// There are no hazard pointers left
+assert!(cell.try_read().is_none());
+
+drop(handle_1);
+assert_eq!(*cell.try_read().unwrap(), 0);
+# drop(handle_2);
+```
+*/
+pub struct FixedDomain<const N: usize> {
+    hzrd_ptrs: [HzrdPtr; N],
+    retired_ptrs: SharedStack<RetiredPtr>,
+    retired_count: AtomicUsize,
+    id: DomainId,
+    on_exhausted: OnExhausted,
+}
+
+impl<const N: usize> Default for FixedDomain<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedDomain<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The retired values can be reclaimed while we format, and so only their count is shown
+        f.debug_struct("FixedDomain")
+            .field("hzrd_ptrs", &self.hzrd_ptrs)
+            .field("retired_count", &self.retired_count)
+            .field("id", &self.id)
+            .field("on_exhausted", &self.on_exhausted)
+            .finish()
+    }
+}
+
+impl<const N: usize> FixedDomain<N> {
+    /**
+    Construct a new, clean domain with `N` hazard pointers
+
+    # Example
+    ```
+    # use hzrd::core::Domain;
+    # use hzrd::domains::FixedDomain;
+    let domain = FixedDomain::<8>::new();
+    assert_eq!(domain.hzrd_ptr_count(), 8);
+    ```
+    */
+    pub fn new() -> Self {
+        let id = DomainId::new();
+        let domain_id = id.get();
+
+        let hzrd_ptrs = core::array::from_fn(|_| {
+            let hzrd_ptr = HzrdPtr::new_in_domain(domain_id);
+            // SAFETY: The hazard pointer has not been handed out yet
+            unsafe { hzrd_ptr.release() };
+            hzrd_ptr
+        });
+
+        Self {
+            hzrd_ptrs,
+            retired_ptrs: SharedStack::new(),
+            retired_count: AtomicUsize::new(0),
+            id,
+            on_exhausted: OnExhausted::Panic,
+        }
+    }
+
+    /**
+    Set what to do when all the hazard pointers are in use (default: [`OnExhausted::Panic`])
+
+    # Example
+    ```
+    # use hzrd::domains::{FixedDomain, OnExhausted};
+    let domain = FixedDomain::<4>::new().on_exhausted(OnExhausted::Wait);
+    ```
+    */
+    pub fn on_exhausted(self, on_exhausted: OnExhausted) -> Self {
+        Self {
+            on_exhausted,
+            ..self
+        }
+    }
+
+    /// Reclaim all "reclaimable" memory, if there are at least `bulk_size` retired pointers
+    fn reclaim_over(&self, bulk_size: usize) -> usize {
+        // Check if it's too small to reclaim
+        if self.retired_count.load(SeqCst) < bulk_size {
+            return 0;
+        }
+
+        // The hazard pointers must be loaded after the retired pointers are taken off the stack,
+        // which happens before the predicate is first called
+        let mut hzrd_ptrs = None;
+        // SAFETY: The retired pointers are never iterated over or popped, not even when formatted
+        let removed = unsafe {
+            self.retired_ptrs.drain_filter(|p| {
+                let hzrd_ptrs = hzrd_ptrs
+                    .get_or_insert_with(|| HzrdPtrs::load(global_config(), &self.hzrd_ptrs));
+                !hzrd_ptrs.protects(p)
+            })
+        };
+        let n_reclaimed = removed.into_iter().count();
+        self.retired_count.fetch_sub(n_reclaimed, SeqCst);
+        n_reclaimed
+    }
+}
+
+unsafe impl<const N: usize> Domain for FixedDomain<N> {
+    fn hzrd_ptr(&self) -> &HzrdPtr {
+        loop {
+            if let Some(hzrd_ptr) = self.try_hzrd_ptr() {
+                return hzrd_ptr;
+            }
+
+            match self.on_exhausted {
+                OnExhausted::Panic => panic!("All {N} hazard pointers of the domain are in use"),
+                #[cfg(feature = "std")]
+                OnExhausted::Wait => std::thread::yield_now(),
+                #[cfg(not(feature = "std"))]
+                OnExhausted::Wait => core::hint::spin_loop(),
+            }
+        }
+    }
+
+    fn try_hzrd_ptr(&self) -> Option<&HzrdPtr> {
+        self.hzrd_ptrs.iter().find_map(HzrdPtr::try_acquire)
+    }
+
+    fn just_retire(&self, ret_ptr: RetiredPtr) {
+        // The count is incremented first, such that it never drops below zero
+        self.retired_count.fetch_add(1, SeqCst);
+        self.retired_ptrs.push(ret_ptr);
+    }
+
+    fn retired_count(&self) -> usize {
+        self.retired_count.load(SeqCst)
+    }
+
+    fn hzrd_ptr_count(&self) -> usize {
+        N
+    }
+
+    fn stats(&self) -> DomainStats {
+        stats(&self.hzrd_ptrs, self.retired_count())
+    }
+
+    fn protection_count(&self, addr: usize) -> usize {
+        protection_count(&self.hzrd_ptrs, addr)
+    }
+
+    fn domain_id(&self) -> usize {
+        self.id.get()
+    }
+
+    fn reclaim(&self) -> usize {
+        self.reclaim_over(global_config().bulk_size)
+    }
+
+    fn reclaim_all(&self) -> usize {
+        self.reclaim_over(1)
+    }
+
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        self.reclaim_over(threshold.saturating_add(1))
+    }
+
+    fn report_long_held(&self, older_than: Duration) -> Vec<LeakInfo> {
+        self.hzrd_ptrs
+            .iter()
+            .filter_map(|hzrd_ptr| hzrd_ptr.leak_info(older_than))
+            .collect()
+    }
+}
+
+// -------------------------------------
+
+/**
+Multithreaded domain which never reclaims memory, until it is dropped
+
+Retired values are only collected, and they are all freed at once when the domain is dropped. Since nothing is reclaimed while the domain is alive the hazard pointers are never scanned, which removes all the overhead of reclamation from writes. The cost is that memory usage grows with every write, which makes the domain suitable for short-lived programs, or for arena-style usage where the domain is dropped after a batch of work.
+
+Calling [`reclaim`](`Domain::reclaim`) or [`reclaim_all`](`Domain::reclaim_all`) on this domain is a no-op.
+
+# Example
+```
+use hzrd::core::Domain;
+use hzrd::domains::NoReclaimDomain;
+use hzrd::HzrdCell;
+
+let domain = NoReclaimDomain::new();
+let cell = HzrdCell::new_in(0, &domain);
+
+for i in 1..=10 {
+    cell.set(i);
+}
+
+// Nothing is freed until the domain is dropped
+assert_eq!(domain.reclaim(), 0);
+assert_eq!(domain.retired_count(), 10);
+```
+*/
+#[derive(Debug)]
+pub struct NoReclaimDomain {
+    hzrd_ptrs: SharedStack<HzrdPtr>,
+    retired_ptrs: SharedStack<RetiredPtr>,
+    retired_count: AtomicUsize,
+    id: DomainId,
+}
+
+impl Default for NoReclaimDomain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NoReclaimDomain {
+    const_fn! {
+        /**
+        Construct a new, clean domain which never reclaims memory
+
+        # Example
+        ```
+        # use hzrd::domains::NoReclaimDomain;
+        let domain = NoReclaimDomain::new();
+        ```
+        */
+        pub fn new() -> Self {
+            Self {
+                hzrd_ptrs: SharedStack::new(),
+                retired_ptrs: SharedStack::new(),
+                retired_count: AtomicUsize::new(0),
+                id: DomainId::new(),
+            }
+        }
+    }
+}
+
+unsafe impl Domain for NoReclaimDomain {
+    fn hzrd_ptr(&self) -> &HzrdPtr {
+        match self.hzrd_ptrs.iter().find_map(|node| node.try_acquire()) {
+            Some(hzrd_ptr) => hzrd_ptr,
+            None => {
+                let hzrd_ptr = HzrdPtr::new_in_domain(self.domain_id());
+                self.hzrd_ptrs.push_get(hzrd_ptr)
+            }
+        }
+    }
+
+    fn just_retire(&self, ret_ptr: RetiredPtr) {
+        self.retired_count.fetch_add(1, SeqCst);
+        self.retired_ptrs.push(ret_ptr);
+    }
+
+    fn retired_count(&self) -> usize {
+        self.retired_count.load(SeqCst)
+    }
+
+    fn hzrd_ptr_count(&self) -> usize {
+        self.hzrd_ptrs.iter().count()
+    }
 
-            // All threads read at the same time!
-            println!("[{i}]: {}", reader.get());
-        });
+    fn stats(&self) -> DomainStats {
+        stats(&self.hzrd_ptrs, self.retired_count())
     }
 
-    // Wait for all the threads to be ready
-    barrier.wait();
+    fn protection_count(&self, addr: usize) -> usize {
+        protection_count(&self.hzrd_ptrs, addr)
+    }
 
-    // Then start counting
-    for num in 1..5 {
-        // Don't perform garbage collection
-        cell.just_set(num);
+    fn domain_id(&self) -> usize {
+        self.id.get()
     }
 
-    // We're done, but no need to clean up...
-});
+    // The retired values are freed when the domain is dropped
+    fn reclaim(&self) -> usize {
+        0
+    }
+}
 
-// The cell is dropped, all garbage is cleaned up
-drop(cell);
+// -------------------------------------
+
+/// The default number of retired values which makes a [`GenerationDomain`] advance to the next generation
+const DEFAULT_GENERATION_SIZE: usize = 64;
+
+/**
+Multithreaded domain where readers announce a generation, which lets whole generations be reclaimed at once
+
+The domain keeps a generation counter, which is advanced every time memory is reclaimed. Retired values are tagged with the generation they were retired in, and every reader announces the current generation when it gets a hazard pointer from the domain. A reader which has announced a later generation than a retired value can never have seen that value, as the value was already swapped out of its cell when the reader arrived.
+
+When reclaiming, the domain finds the oldest generation announced by a reader which is currently protecting a value. Every value retired before this generation is freed right away, without looking up its address. Only the values retired since are checked against the addresses held by the hazard pointers, just like in a [`SharedDomain`]. Readers which keep their hazard pointer for a long time, such as a [`HzrdReader`](crate::HzrdReader), only announce a generation when the hazard pointer is first acquired, and so the values they may protect are always checked by address.
+
+Memory is only reclaimed once a number of values have been retired, which can be set with [`with_generation_size`](GenerationDomain::with_generation_size).
+
+# Example
+```
+use hzrd::core::Domain;
+use hzrd::domains::GenerationDomain;
+use hzrd::HzrdCell;
+
+let domain = GenerationDomain::with_generation_size(4);
+let cell = HzrdCell::new_in(0, &domain);
+
+// Nothing is reclaimed until enough values have been retired
+cell.set(1);
+cell.set(2);
+cell.set(3);
+assert_eq!(domain.retired_count(), 3);
+
+cell.set(4);
+assert_eq!(domain.retired_count(), 0);
+assert_eq!(domain.generation(), 1);
 ```
 */
+pub struct GenerationDomain {
+    slots: SharedStack<GenerationSlot>,
+    // Retired values, tagged with the generation they were retired in
+    retired_ptrs: SharedStack<(usize, RetiredPtr)>,
+    retired_count: AtomicUsize,
+    generation: AtomicUsize,
+    generation_size: usize,
+    id: DomainId,
+}
+
+/// A hazard pointer of a [`GenerationDomain`], along with the generation announced by its reader
 #[derive(Debug)]
-pub struct LocalDomain {
-    // Important to only allow shared references to the HzrdPtr's
-    hzrd_ptrs: UnsafeCell<LinkedList<SharedCell<HzrdPtr>>>,
-    retired_ptrs: UnsafeCell<Vec<RetiredPtr>>,
+struct GenerationSlot {
+    hzrd_ptr: HzrdPtr,
+    // The generation when the hazard pointer was acquired, values retired before it can not be protected
+    generation: AtomicUsize,
 }
 
-impl Default for LocalDomain {
+impl Default for GenerationDomain {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl LocalDomain {
+impl core::fmt::Debug for GenerationDomain {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The retired values can be reclaimed while we format, and so only their count is shown
+        f.debug_struct("GenerationDomain")
+            .field("slots", &self.slots)
+            .field("retired_count", &self.retired_count)
+            .field("generation", &self.generation)
+            .field("generation_size", &self.generation_size)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl GenerationDomain {
+    const_fn! {
+        /**
+        Construct a new, clean domain with the default generation size
+
+        # Example
+        ```
+        # use hzrd::domains::GenerationDomain;
+        let domain = GenerationDomain::new();
+        ```
+        */
+        pub fn new() -> Self {
+            Self::with_generation_size(DEFAULT_GENERATION_SIZE)
+        }
+    }
+
+    const_fn! {
+        /**
+        Construct a new, clean domain which advances to the next generation once the given number of values are retired
+
+        # Example
+        ```
+        # use hzrd::domains::GenerationDomain;
+        let domain = GenerationDomain::with_generation_size(1024);
+        ```
+        */
+        pub fn with_generation_size(generation_size: usize) -> Self {
+            Self {
+                slots: SharedStack::new(),
+                retired_ptrs: SharedStack::new(),
+                retired_count: AtomicUsize::new(0),
+                generation: AtomicUsize::new(0),
+                generation_size,
+                id: DomainId::new(),
+            }
+        }
+    }
+
     /**
-    Construct a new, clean local domain
+    Get the current generation, which is the number of times the domain has reclaimed memory
 
     # Example
     ```
-    # use hzrd::domains::LocalDomain;
-    let domain = LocalDomain::new();
+    use hzrd::core::Domain;
+    use hzrd::domains::GenerationDomain;
+
+    let domain = GenerationDomain::new();
+    assert_eq!(domain.generation(), 0);
+    domain.reclaim_all();
+    assert_eq!(domain.generation(), 1);
     ```
     */
-    pub const fn new() -> Self {
-        Self {
-            hzrd_ptrs: UnsafeCell::new(LinkedList::new()),
-            retired_ptrs: UnsafeCell::new(Vec::new()),
-        }
+    pub fn generation(&self) -> usize {
+        self.generation.load(SeqCst)
     }
 
-    #[cfg(test)]
-    pub(crate) fn number_of_hzrd_ptrs(&self) -> usize {
-        unsafe { (*self.hzrd_ptrs.get()).len() }
+    fn hzrd_ptrs(&self) -> impl Iterator<Item = &HzrdPtr> {
+        self.slots.iter().map(|slot| &slot.hzrd_ptr)
     }
 
-    #[cfg(test)]
-    pub(crate) fn number_of_retired_ptrs(&self) -> usize {
-        unsafe { (*self.retired_ptrs.get()).len() }
+    fn protected_addrs(&self) -> AddrSet {
+        self.hzrd_ptrs()
+            .filter_map(HzrdPtr::protected_addr)
+            .collect()
+    }
+
+    /// Advance to the next generation, and free all values which are no longer protected
+    fn advance(&self) -> usize {
+        // Readers arriving after this can only see values which are not yet retired
+        self.generation.fetch_add(1, SeqCst);
+
+        // The readers must be checked after the retired values are taken off the stack, which
+        // happens before the predicate is first called
+        let mut oldest_reader = None;
+        // The addresses are only looked up if some value was retired after the oldest reader arrived
+        let mut protected: Option<AddrSet> = None;
+
+        // SAFETY: The retired values are never iterated over or popped, not even when formatted
+        let removed = unsafe {
+            self.retired_ptrs.drain_filter(|(generation, retired_ptr)| {
+                let oldest_reader = *oldest_reader.get_or_insert_with(|| {
+                    // Pairs with the fence issued by readers between protecting a value and verifying it
+                    fence(SeqCst);
+                    self.slots
+                        .iter()
+                        .filter(|slot| slot.hzrd_ptr.protected_addr().is_some())
+                        .map(|slot| slot.generation.load(SeqCst))
+                        .min()
+                        .unwrap_or(usize::MAX)
+                });
+
+                *generation < oldest_reader
+                    || !protected
+                        .get_or_insert_with(|| self.protected_addrs())
+                        .contains(&retired_ptr.addr())
+            })
+        };
+
+        // The removed values are freed as they are dropped
+        let reclaimed = removed.into_iter().count();
+        self.retired_count.fetch_sub(reclaimed, SeqCst);
+        reclaimed
     }
 }
 
-unsafe impl Domain for LocalDomain {
+unsafe impl Domain for GenerationDomain {
     fn hzrd_ptr(&self) -> &HzrdPtr {
+        let slot = match self
+            .slots
+            .iter()
+            .find(|slot| slot.hzrd_ptr.try_acquire().is_some())
         {
-            let hzrd_ptrs = unsafe { &*self.hzrd_ptrs.get() };
+            Some(slot) => slot,
+            None => self.slots.push_get(GenerationSlot {
+                hzrd_ptr: HzrdPtr::new_in_domain(self.domain_id()),
+                generation: AtomicUsize::new(0),
+            }),
+        };
+
+        // Announced before the reader protects anything, values retired before this are already out of reach
+        slot.generation.store(self.generation.load(SeqCst), SeqCst);
+        &slot.hzrd_ptr
+    }
 
-            if let Some(hzrd_ptr) = hzrd_ptrs.iter().find_map(|node| node.get().try_acquire()) {
-                return hzrd_ptr;
-            }
-        }
+    fn just_retire(&self, ret_ptr: RetiredPtr) {
+        // The value is already swapped out, and so readers announcing a later generation can never see it
+        let generation = self.generation.load(SeqCst);
 
-        let hzrd_ptrs = unsafe { &mut *self.hzrd_ptrs.get() };
-        hzrd_ptrs.push_back(SharedCell::new(HzrdPtr::new()));
-        unsafe { hzrd_ptrs.back().unwrap_unchecked().get() }
+        // The count is incremented first, such that it never drops below zero
+        self.retired_count.fetch_add(1, SeqCst);
+        self.retired_ptrs.push((generation, ret_ptr));
     }
 
-    fn just_retire(&self, ret_ptr: RetiredPtr) {
-        let retired_ptrs = unsafe { &mut *self.retired_ptrs.get() };
-        retired_ptrs.push(ret_ptr);
+    fn retired_count(&self) -> usize {
+        self.retired_count.load(SeqCst)
     }
 
-    fn reclaim(&self) -> usize {
-        let retired_ptrs = unsafe { &mut *self.retired_ptrs.get() };
-        let hzrd_ptrs = unsafe { &mut *self.hzrd_ptrs.get() };
+    fn hzrd_ptr_count(&self) -> usize {
+        self.slots.iter().count()
+    }
 
-        let prev_size = retired_ptrs.len();
+    fn stats(&self) -> DomainStats {
+        stats(self.hzrd_ptrs(), self.retired_count())
+    }
 
-        // Check if it's too small to reclaim
-        if prev_size < global_config().bulk_size {
-            return 0;
+    fn protection_count(&self, addr: usize) -> usize {
+        protection_count(self.hzrd_ptrs(), addr)
+    }
+
+    fn domain_id(&self) -> usize {
+        self.id.get()
+    }
+
+    fn reclaim(&self) -> usize {
+        match self.retired_count() >= self.generation_size {
+            true => self.advance(),
+            false => 0,
         }
+    }
 
-        let hzrd_ptrs = HzrdPtrs::load(hzrd_ptrs.iter().map(SharedCell::get));
-        retired_ptrs.retain(|p| hzrd_ptrs.contains(p.addr()));
-        prev_size - retired_ptrs.len()
+    fn reclaim_all(&self) -> usize {
+        self.advance()
     }
-}
 
+    fn reclaim_if_over(&self, threshold: usize) -> usize {
+        match self.retired_count() > threshold {
+            true => self.advance(),
+            false => 0,
+        }
+    }
+}
 // -------------------------------------
 
 #[cfg(test)]
 mod tests {
     use std::ptr::NonNull;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
 
     use super::*;
 
@@ -523,7 +2070,21 @@ mod tests {
         unsafe { NonNull::new_unchecked(raw) }
     }
 
+    /// Increments the counter when dropped
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+
     #[test]
+    #[cfg(not(all(
+        feature = "single-thread",
+        target_arch = "wasm32",
+        not(target_feature = "atomics")
+    )))]
     fn global_domain() {
         let ptr = new_value(['a', 'b', 'c', 'd']);
         let domain = GlobalDomain;
@@ -532,7 +2093,7 @@ mod tests {
         assert_eq!(domain.number_of_hzrd_ptrs(), 1);
 
         unsafe { hzrd_ptr.protect(ptr.as_ptr()) };
-        let hzrd_ptrs = HzrdPtrs::load(GLOBAL_DOMAIN.hzrd_ptrs.iter());
+        let hzrd_ptrs = HzrdPtrs::load(global_config(), &GLOBAL_DOMAIN.hzrd_ptrs);
         assert!(hzrd_ptrs.contains(ptr.as_ptr() as usize));
 
         // Retire the pointer. Nothing should be reclaimed this time
@@ -569,7 +2130,7 @@ mod tests {
         assert_eq!(domain.number_of_hzrd_ptrs(), 1);
 
         unsafe { hzrd_ptr.protect(ptr.as_ptr()) };
-        let hzrd_ptrs = HzrdPtrs::load(domain.hzrd_ptrs.iter());
+        let hzrd_ptrs = HzrdPtrs::load(global_config(), &domain.hzrd_ptrs);
         assert!(hzrd_ptrs.contains(ptr.as_ptr() as usize));
 
         // Retire the pointer. Nothing should be reclaimed this time
@@ -597,6 +2158,560 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn report_long_held() {
+        use crate::HzrdCell;
+
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+
+        let handle = cell.read();
+        let _short_lived = cell.read();
+
+        std::thread::sleep(Duration::from_millis(50));
+        drop(_short_lived);
+        let _new_handle = cell.read();
+
+        let leaks = domain.report_long_held(Duration::from_millis(20));
+        assert_eq!(leaks.len(), 1, "{leaks:?}");
+        assert_eq!(leaks[0].addr, &*handle as *const String as usize);
+        assert!(leaks[0].held_for >= Duration::from_millis(50));
+
+        drop(handle);
+        assert!(domain
+            .report_long_held(Duration::from_millis(20))
+            .is_empty());
+    }
+
+    #[test]
+    fn recycling_domain() {
+        use crate::HzrdCell;
+
+        let domain = RecyclingDomain::new();
+        let cell = HzrdCell::new_in(Vec::with_capacity(64), &domain);
+
+        let mut pool: Vec<Box<Vec<usize>>> = Vec::new();
+        let mut allocations = 0;
+        let mut pending = 0;
+
+        for i in 0..100 {
+            let mut buffer = pool.pop().unwrap_or_else(|| {
+                allocations += 1;
+                Box::new(Vec::with_capacity(64))
+            });
+            buffer.clear();
+            buffer.extend(0..i);
+
+            // The value being swapped out is protected, and can not be recycled yet
+            let handle = cell.read();
+            cell.just_set_boxed(buffer);
+            pending += 1;
+
+            if i % 10 == 9 {
+                assert_eq!(domain.reclaim_into_pool(&mut pool), pending - 1);
+                assert!(pool.iter().all(|b| !std::ptr::eq(&**b, &*handle)));
+                pending = 1;
+            }
+        }
+
+        // New buffers are only needed until the first batch is recycled
+        assert_eq!(allocations, 11);
+        assert!(pool.iter().all(|buffer| buffer.capacity() >= 64));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Hazard pointer from domain")]
+    fn mismatched_domains() {
+        use std::sync::atomic::AtomicPtr;
+
+        use crate::core::{Action, ReadHandle};
+
+        let domain_1 = SharedDomain::new();
+        let domain_2 = LocalDomain::new();
+        assert_ne!(domain_1.domain_id(), domain_2.domain_id());
+
+        let mut value = 0;
+        let value = AtomicPtr::new(&mut value);
+        let hzrd_ptr = domain_2.hzrd_ptr();
+        let _ =
+            unsafe { ReadHandle::read_unchecked_in(&value, hzrd_ptr, Action::Release, &domain_1) };
+    }
+
+    #[test]
+    fn retired_count() {
+        use crate::HzrdCell;
+
+        let shared_domain = SharedDomain::new();
+        let local_domain = LocalDomain::new();
+        let shared_cell = HzrdCell::new_in(0, &shared_domain);
+        let local_cell = HzrdCell::new_in(0, &local_domain);
+
+        let _shared_handle = shared_cell.read();
+        let _local_handle = local_cell.read();
+
+        std::thread::scope(|s| {
+            for i in 0..4 {
+                let shared_cell = &shared_cell;
+                s.spawn(move || shared_cell.set(i));
+            }
+        });
+        (0..4).for_each(|i| local_cell.set(i));
+
+        // Writing does not require any hazard pointers, only the handles hold one
+        assert_eq!(shared_domain.hzrd_ptr_count(), 1);
+        assert_eq!(local_domain.hzrd_ptr_count(), 1);
+
+        // Only the values protected by the handles should remain
+        assert_eq!(shared_domain.retired_count(), 1);
+        assert_eq!(shared_domain.number_of_retired_ptrs(), 1);
+        assert_eq!(local_domain.retired_count(), 1);
+
+        drop(_shared_handle);
+        shared_cell.reclaim();
+        assert_eq!(shared_domain.retired_count(), 0);
+    }
+
+    #[test]
+    fn reclaim_with_budget() {
+        use crate::HzrdCell;
+
+        fn reclaim_in_steps(domain: impl Domain) {
+            let cell = HzrdCell::new_in(0, &domain);
+            for i in 1..=10 {
+                cell.just_set(i);
+            }
+
+            // A protected value should not stop the rest from being reclaimed
+            let handle = cell.read();
+            cell.just_set(11);
+
+            // There is one hazard pointer, so each check costs a single comparison
+            assert_eq!(domain.hzrd_ptr_count(), 1);
+            let mut reclaimed = Vec::new();
+            while domain.retired_count() > 1 {
+                reclaimed.push(domain.try_reclaim_with_budget(4));
+            }
+            assert_eq!(reclaimed.iter().sum::<usize>(), 10);
+            assert!(reclaimed.iter().all(|&n| n <= 4));
+
+            drop(handle);
+            assert_eq!(domain.try_reclaim_with_budget(0), 0);
+            assert_eq!(domain.try_reclaim_with_budget(1), 1);
+            assert_eq!(domain.retired_count(), 0);
+        }
+
+        reclaim_in_steps(SharedDomain::new());
+        reclaim_in_steps(LocalDomain::new());
+    }
+
+    #[test]
+    fn reclaim_with_budget_past_protected() {
+        use crate::HzrdCell;
+
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(0, &domain);
+        for i in 1..=4 {
+            cell.just_set(i);
+        }
+
+        // The two most recently retired values are protected
+        let first = cell.read();
+        cell.just_set(5);
+        let second = cell.read();
+        cell.just_set(6);
+
+        // Each call checks two values, the protected ones should not be checked again and again
+        assert_eq!(domain.hzrd_ptr_count(), 2);
+        let reclaimed: usize = (0..3).map(|_| domain.try_reclaim_with_budget(4)).sum();
+        assert_eq!(reclaimed, 4);
+        assert_eq!(domain.retired_count(), 2);
+
+        drop((first, second));
+        assert_eq!(domain.try_reclaim_with_budget(4), 2);
+    }
+
+    #[test]
+    fn shared_domain_merge() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(0, &domain);
+        let handle = cell.read();
+        cell.set(1);
+
+        let other = SharedDomain::new();
+        for i in 0..4 {
+            other.just_retire(unsafe { RetiredPtr::new(new_value(i)) });
+        }
+        assert_eq!(other.retired_count(), 4);
+
+        // The merged values are reclaimed along with the ones already retired
+        domain.merge(other);
+        assert_eq!(domain.retired_count(), 5);
+        assert_eq!(domain.reclaim(), 4);
+        drop(handle);
+        assert_eq!(domain.reclaim(), 1);
+    }
+
+    #[test]
+    fn debug_while_reclaiming() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(0, &domain);
+        cell.just_set(1);
+        assert!(format!("{domain:?}").contains("retired_count: 1"));
+
+        // Formatting only shows the number of retired values, and so it may run during reclamation
+        std::thread::scope(|s| {
+            s.spawn(|| (0..100).for_each(|i| cell.set(i)));
+            for _ in 0..100 {
+                let _ = format!("{domain:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn reclaim_if_over() {
+        use crate::HzrdCell;
+
+        fn reclaim_over_threshold(domain: impl Domain) {
+            let cell = HzrdCell::new_in(0, &domain);
+            for i in 1..=4 {
+                cell.just_set(i);
+            }
+
+            // The configured bulk size is ignored in favour of the threshold
+            assert_eq!(domain.reclaim_if_over(4), 0);
+            assert_eq!(domain.retired_count(), 4);
+            assert_eq!(domain.reclaim_if_over(3), 4);
+            assert_eq!(domain.retired_count(), 0);
+            assert_eq!(domain.reclaim_if_over(0), 0);
+        }
+
+        let config = Config::default().bulk_size(100);
+        reclaim_over_threshold(SharedDomain::with_config(config));
+        reclaim_over_threshold(LocalDomain::with_config(config));
+        reclaim_over_threshold(FixedDomain::<1>::new());
+    }
+
+    #[test]
+    fn reclaim_strategies() {
+        use crate::HzrdCell;
+
+        let strategies = [
+            ReclaimStrategy::Auto,
+            ReclaimStrategy::Linear,
+            ReclaimStrategy::Sorted,
+            ReclaimStrategy::Hashed,
+        ];
+
+        // With enough hazard pointers the automatic strategy switches to a hash set
+        let configs = strategies.into_iter().flat_map(|strategy| {
+            [(false, 0), (true, 0), (false, 100), (true, 100)].map(|(caching, preallocate)| {
+                Config::default()
+                    .reclaim_strategy(strategy)
+                    .caching(caching)
+                    .preallocate(preallocate)
+            })
+        });
+
+        for config in configs {
+            let domain = SharedDomain::with_config(config);
+            let cells: Vec<_> = (0..8).map(|i| HzrdCell::new_in(i, &domain)).collect();
+
+            // Protect the current value of every other cell, then retire all of them
+            let handles: Vec<_> = cells.iter().step_by(2).map(HzrdCell::read).collect();
+            cells.iter().for_each(|cell| cell.just_set(-1));
+            assert_eq!(domain.reclaim(), 4, "{config:?}");
+            assert!(handles.iter().zip((0..8).step_by(2)).all(|(h, i)| **h == i));
+
+            drop(handles);
+            assert_eq!(domain.reclaim(), 4, "{config:?}");
+        }
+    }
+
+    #[test]
+    fn drop_frees_retired() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(DropCounter(Arc::clone(&drops)), &domain);
+
+        // The first value is protected by a handle which is never dropped
+        std::mem::forget(cell.read());
+        for _ in 0..100 {
+            cell.just_set(DropCounter(Arc::clone(&drops)));
+        }
+
+        cell.reclaim();
+        assert_eq!(drops.load(SeqCst), 99);
+        assert_eq!(domain.retired_count(), 1);
+
+        drop(cell);
+        drop(domain);
+        assert_eq!(drops.load(SeqCst), 101);
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn warn_long_held() {
+        let config = Config::default().warn_long_held(3);
+        let domain = SharedDomain::with_config(config);
+        let ptr = new_value(0);
+        let retired_ptr = unsafe { RetiredPtr::new(ptr) };
+
+        // Only reclamations where the value is protected are counted
+        let hzrd_ptr = domain.hzrd_ptr();
+        assert!(!HzrdPtrs::load(&config, &domain.hzrd_ptrs).protects(&retired_ptr));
+        unsafe { hzrd_ptr.protect(ptr.as_ptr()) };
+        let warnings = LONG_HELD_WARNINGS.load(SeqCst);
+        for _ in 0..10 {
+            assert!(HzrdPtrs::load(&config, &domain.hzrd_ptrs).protects(&retired_ptr));
+        }
+
+        // The warning is only printed once, after the third reclamation
+        assert_eq!(LONG_HELD_WARNINGS.load(SeqCst) - warnings, 1);
+        assert_eq!(retired_ptr.protected_through_reclaim(), 11);
+
+        unsafe { hzrd_ptr.release() };
+    }
+
+    #[test]
+    fn generation_domain() {
+        use crate::HzrdCell;
+
+        let domain = GenerationDomain::with_generation_size(4);
+        let cell = HzrdCell::new_in(0, &domain);
+        let old_handle = cell.read();
+
+        // Reclaiming frees everything but the protected value
+        (1..=4).for_each(|i| cell.set(i));
+        assert_eq!(domain.generation(), 1);
+        assert_eq!(domain.retired_count(), 1);
+
+        // A reader arriving in a later generation protects a value retired in that generation
+        let new_handle = cell.read();
+        (5..=7).for_each(|i| cell.set(i));
+        assert_eq!(domain.generation(), 2);
+        assert_eq!(domain.retired_count(), 2);
+        assert_eq!((*old_handle, *new_handle), (0, 4));
+
+        drop(old_handle);
+        assert_eq!(domain.reclaim_if_over(1), 1);
+        assert_eq!(domain.retired_count(), 1);
+        drop(new_handle);
+        assert_eq!(domain.reclaim_all(), 1);
+        assert_eq!(cell.get(), 7);
+    }
+
+    #[test]
+    fn generation_domain_long_lived_reader() {
+        use crate::HzrdCell;
+
+        let domain = GenerationDomain::with_generation_size(1);
+        let cell = HzrdCell::new_in(0, &domain);
+
+        // The reader announces its generation once, and later reads are checked by address
+        let mut reader = cell.reader();
+        cell.set(1);
+        cell.set(2);
+        let handle = reader.read();
+        cell.set(3);
+        assert_eq!(domain.retired_count(), 1);
+        assert_eq!(*handle, 2);
+
+        drop(handle);
+        assert_eq!(domain.reclaim_all(), 1);
+    }
+
+    #[test]
+    #[cfg(not(all(
+        feature = "single-thread",
+        target_arch = "wasm32",
+        not(target_feature = "atomics")
+    )))]
+    fn generation_domain_threads() {
+        use crate::HzrdCell;
+
+        let domain = GenerationDomain::with_generation_size(16);
+        let cell = HzrdCell::new_in(0, &domain);
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..100 {
+                        let value = *cell.read();
+                        cell.set(value + 1);
+                    }
+                });
+            }
+        });
+
+        domain.reclaim_all();
+        assert_eq!(domain.retired_count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn flush_thread_cache() {
+        fn cache_capacity() -> usize {
+            HAZARD_POINTERS_CACHE.with(|cell| {
+                let cache = cell.take();
+                let capacity = cache.capacity();
+                cell.set(cache);
+                capacity
+            })
+        }
+
+        let config = Config::default().caching(true).preallocate(100);
+        let domain = SharedDomain::with_config(config);
+        std::thread::spawn(move || {
+            assert_eq!(cache_capacity(), 0);
+            let ptr = new_value(0);
+            unsafe { domain.retire(RetiredPtr::new(ptr)) };
+            assert!(cache_capacity() >= 100);
+
+            super::flush_thread_cache();
+            assert_eq!(cache_capacity(), 0);
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn stats() {
+        fn check_stats(domain: impl Domain, total_after_release: usize) {
+            let cell = HzrdCell::new_in(0, &domain);
+            assert_eq!(domain.stats().retired_pending, 0);
+
+            let handles = [cell.read(), cell.read()];
+            cell.just_set(1);
+            let stats = domain.stats();
+            assert_eq!(stats.hzrd_ptrs_active, 2);
+            assert_eq!(stats.retired_pending, 1);
+
+            drop(handles);
+            cell.reclaim();
+            let stats = domain.stats();
+            assert_eq!(stats.hzrd_ptrs_total, total_after_release);
+            assert_eq!(stats.hzrd_ptrs_active, 0);
+        }
+
+        check_stats(SharedDomain::new(), 2);
+        check_stats(LocalDomain::new(), 2);
+        check_stats(FixedDomain::<4>::new(), 4);
+        check_stats(Arc::new(RecyclingDomain::<i32>::new()), 2);
+    }
+
+    #[test]
+    fn local_reader() {
+        let domain = LocalDomain::new();
+        let cells = [HzrdCell::new_in(1, &domain), HzrdCell::new_in(2, &domain)];
+
+        // A single hazard pointer is used for all reads
+        let mut reader = domain.reader_handle();
+        let sum: i32 = cells.iter().cycle().take(10).map(|c| *reader.read(c)).sum();
+        assert_eq!(sum, 15);
+        assert_eq!(domain.hzrd_ptr_count(), 1);
+
+        let handle = reader.read(&cells[0]);
+        cells[0].set(3);
+        assert_eq!(domain.retired_count(), 1);
+        drop(handle);
+        cells[0].reclaim();
+        assert_eq!(domain.retired_count(), 0);
+
+        // The hazard pointer is released when the reader is dropped
+        drop(reader);
+        let _handle = cells[1].read();
+        assert_eq!(domain.hzrd_ptr_count(), 1);
+
+        // Cells holding the domain in any form which forwards to it can be read
+        let domain = std::rc::Rc::new(LocalDomain::new());
+        let cell = HzrdCell::new_in(4, std::rc::Rc::clone(&domain));
+        assert_eq!(*domain.reader_handle().read(&cell), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "The cell does not belong to the domain of the reader")]
+    fn local_reader_other_domain() {
+        let domain = LocalDomain::new();
+        let cell = HzrdCell::new_in(0, LocalDomain::new());
+        let _ = *domain.reader_handle().read(&cell);
+    }
+
+    #[test]
+    fn no_reclaim() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let domain = NoReclaimDomain::new();
+        let cell = HzrdCell::new_in(DropCounter(Arc::clone(&drops)), &domain);
+
+        for _ in 0..10 {
+            cell.set(DropCounter(Arc::clone(&drops)));
+        }
+        cell.reclaim_all();
+        assert_eq!(drops.load(SeqCst), 0);
+        assert_eq!(domain.retired_count(), 10);
+
+        drop(cell);
+        assert_eq!(drops.load(SeqCst), 1);
+        drop(domain);
+        assert_eq!(drops.load(SeqCst), 11);
+    }
+
+    #[test]
+    fn preallocate() {
+        use crate::HzrdCell;
+
+        let config = Config::default().preallocate(3);
+        let shared_domain = SharedDomain::with_config(config);
+        let local_domain = LocalDomain::with_config(config);
+        assert_eq!(shared_domain.hzrd_ptr_count(), 3);
+        assert_eq!(local_domain.hzrd_ptr_count(), 3);
+
+        // Reads use the preallocated hazard pointers
+        let shared_cell = HzrdCell::new_in(0, &shared_domain);
+        let local_cell = HzrdCell::new_in(0, &local_domain);
+        let handles = [shared_cell.read(), shared_cell.read(), shared_cell.read()];
+        let _handle = local_cell.read();
+        assert_eq!(shared_domain.hzrd_ptr_count(), 3);
+        assert_eq!(local_domain.hzrd_ptr_count(), 3);
+
+        let _handle = shared_cell.read();
+        assert_eq!(shared_domain.hzrd_ptr_count(), 4);
+        drop(handles);
+    }
+
+    #[test]
+    fn fixed_domain() {
+        use crate::HzrdCell;
+
+        let domain = FixedDomain::<2>::new();
+        let cell = HzrdCell::new_in(0, &domain);
+
+        let handle_1 = cell.read();
+        let handle_2 = cell.try_read().unwrap();
+        assert!(domain.try_hzrd_ptr().is_none());
+        assert!(cell.try_read().is_none());
+
+        // Values can still be retired and reclaimed
+        cell.set(1);
+        assert_eq!(domain.retired_count(), 1);
+        drop((handle_1, handle_2));
+        cell.reclaim();
+        assert_eq!(domain.retired_count(), 0);
+
+        // The hazard pointers are reused
+        assert_eq!(*cell.read(), 1);
+        assert_eq!(domain.hzrd_ptr_count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "All 1 hazard pointers of the domain are in use")]
+    fn fixed_domain_exhausted() {
+        let domain = FixedDomain::<1>::new();
+        let _hzrd_ptr = domain.hzrd_ptr();
+        let _ = domain.hzrd_ptr();
+    }
+
     #[test]
     fn local_domain() {
         let ptr = new_value(['a', 'b', 'c', 'd']);
@@ -607,7 +2722,7 @@ mod tests {
 
         unsafe { hzrd_ptr.protect(ptr.as_ptr()) };
         let hzrd_ptrs = unsafe { &*domain.hzrd_ptrs.get() };
-        let hzrd_ptrs = HzrdPtrs::load(hzrd_ptrs.iter().map(SharedCell::get));
+        let hzrd_ptrs = HzrdPtrs::load(global_config(), hzrd_ptrs.iter().map(SharedCell::get));
         assert!(hzrd_ptrs.contains(ptr.as_ptr() as usize));
 
         // Retire the pointer. Nothing should be reclaimed this time
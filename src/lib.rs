@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(unsafe_op_in_unsafe_fn)]
 #![warn(missing_docs)]
 //#![warn(rustdoc::missing_doc_code_examples)]
@@ -38,12 +39,43 @@ std::thread::scope(|s| {
     });
 });
 ```
+
+# Imports
+The three domains provided by this crate are re-exported at the crate root, so both `hzrd::SharedDomain` and `hzrd::domains::SharedDomain` refer to the same type. The most commonly used types can also be imported all at once through the [`prelude`].
+
+# `no_std`
+The crate depends on the standard library through the `std` feature, which is enabled by default. Without it the crate is `no_std`, and only depends on `alloc`. The cells and domains work the same, but the following is only available with `std`:
+- The [`GLOBAL_CONFIG`](crate::domains::GLOBAL_CONFIG), domains not given their own config use the default config instead
+- The thread-local cache used when [caching](crate::domains::Config::caching) is enabled
+- The `buffers` module, the global reclaimer thread, and the `async` feature
+- Timestamps for [long-held hazard pointers](crate::core::Domain::report_long_held), and warnings about them
 */
 
+extern crate alloc;
+
+#[macro_use]
+mod sync;
+
 mod stack;
 
+pub mod arc_cell;
+pub mod boxed;
+#[cfg(feature = "std")]
+pub mod buffers;
 pub mod core;
 pub mod domains;
+pub mod pool;
+pub mod prelude;
+pub mod seqlock;
+pub mod shared_cell;
+pub mod state;
+pub mod strategy;
+
+#[cfg(feature = "std")]
+mod reclaimer;
+
+#[cfg(feature = "async")]
+mod wakers;
 
 mod private {
     // We want to test the code in the readme
@@ -52,11 +84,21 @@ mod private {
 
 // ------------------------------------------
 
-use std::ptr::NonNull;
-use std::sync::atomic::{AtomicPtr, Ordering::*};
+use ::core::ptr::NonNull;
+use ::core::sync::atomic::Ordering::*;
+#[cfg(feature = "async")]
+use ::core::task::{Context, Poll};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 use crate::core::{Action, Domain, HzrdPtr, ReadHandle, RetiredPtr};
-use crate::domains::GlobalDomain;
+use crate::stack::SharedStack;
+use crate::sync::AtomicPtr;
+
+pub use crate::domains::{GlobalDomain, LocalDomain, SharedDomain};
+#[cfg(feature = "std")]
+pub use crate::reclaimer::{spawn_global_reclaimer, ReclaimerHandle};
 
 // -------------------------------------
 
@@ -66,10 +108,28 @@ Holds a value protected by hazard pointers.
 Each [`HzrdCell`] belongs to a given domain, which contains the set of hazard pointers protecting the value. See the [`Domain`] trait for more details on this.
 
 See the [crate-level documentation](crate) for a "getting started" guide.
+
+# Thread safety
+A [`HzrdCell`] can only be shared across threads if both the value held and the domain are thread-safe. This means a cell in a singlethreaded domain, such as [`LocalDomain`](`crate::domains::LocalDomain`), is never [`Sync`], and so there is no need for the value to be [`Sync`] either. Values which are not [`Sync`] can therefore be used freely in local cells:
+
+```
+use std::cell::Cell;
+
+use hzrd::domains::LocalDomain;
+use hzrd::HzrdCell;
+
+let cell = HzrdCell::new_in(Cell::new(0), LocalDomain::new());
+cell.read().set(1);
+assert_eq!(cell.read().get(), 1);
+```
+
+The same cell in the [`GlobalDomain`] can still be constructed and used locally, but it can not be shared across threads.
 */
 pub struct HzrdCell<T, D = GlobalDomain> {
     value: AtomicPtr<T>,
     domain: D,
+    #[cfg(feature = "async")]
+    wakers: wakers::WakerRegistry,
 }
 
 impl<T: 'static> HzrdCell<T> {
@@ -91,6 +151,39 @@ impl<T: 'static> HzrdCell<T> {
     pub fn new(value: T) -> Self {
         Self::new_in(value, GlobalDomain)
     }
+
+    /**
+    Construct a new [`HzrdCell`] from an already boxed value in the default domain.
+
+    The box is used directly as the allocation of the value, so no new allocation is made. This can also be written as `HzrdCell::from(boxed)`.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let boxed = Box::new([0_u8; 1024]);
+    let cell = HzrdCell::new_boxed(boxed);
+    # assert_eq!(cell.read()[0], 0);
+    ```
+    */
+    pub fn new_boxed(boxed: Box<T>) -> Self {
+        Self::new_boxed_in(boxed, GlobalDomain)
+    }
+
+    /**
+    Try to construct a new [`HzrdCell`] with the given value in the default domain, returning the value if allocation fails
+
+    See [`HzrdCell::try_new_in`] for more details.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::try_new(0).unwrap();
+    # assert_eq!(cell.get(), 0);
+    ```
+    */
+    pub fn try_new(value: T) -> Result<Self, T> {
+        Self::try_new_in(value, GlobalDomain)
+    }
 }
 
 impl<T: 'static, D: Domain> HzrdCell<T, D> {
@@ -112,452 +205,3026 @@ impl<T: 'static, D: Domain> HzrdCell<T, D> {
     ```
     */
     pub fn set(&self, value: T) {
-        // SAFETY: We retire the pointer in a valid domain
-        let old_ptr = unsafe { self.swap(Box::new(value)) };
-        self.domain.retire(old_ptr);
-    }
-
-    /// Set the value of the cell without attempting to reclaim memory
-    pub fn just_set(&self, value: T) {
-        // SAFETY: We retire the pointer in a valid domain
-        let old_ptr = unsafe { self.swap(Box::new(value)) };
-        self.domain.just_retire(old_ptr);
+        self.set_reclaiming(value);
     }
 
     /**
-    Get a handle holding a reference to the current value held by the [`HzrdCell`]
-
-    The functionality of this is somewhat similar to a [`MutexGuard`](std::sync::MutexGuard), except the [`ReadHandle`] only accepts reading the value. There is no locking mechanism needed to grab this handle, although there might be a short wait if the read overlaps with a write.
+    Set the value of the cell, and return the number of values reclaimed
 
-    The [`ReadHandle`] acquired holds a shared reference to the value of the [`HzrdCell`] as it was when the [`read`](Self::read) function was called. If the value of the [`HzrdCell`] is changed after the [`ReadHandle`] is acquired its new value is not reflected in the value of the [`ReadHandle`]. The hazard pointer held by the handle will keep the old value alive. See the documentation of [`ReadHandle`] for more information.
+    This is the same as [`set`](HzrdCell::set), but the number of values freed by the write is returned, which can be useful when tuning reclamation, e.g. with [`Config::bulk_size`](crate::domains::Config::bulk_size).
 
     # Example
     ```
     # use hzrd::HzrdCell;
-    #
-    let cell = HzrdCell::new(String::from("Hey"));
-
+    # use hzrd::domains::SharedDomain;
+    let cell = HzrdCell::new_in(0, SharedDomain::new());
     let handle = cell.read();
 
-    // We can create multiple references from a single handle
-    let string: &str = &*handle;
-    let bytes: &[u8] = handle.as_bytes();
+    // The old value is protected by the handle, and can not be reclaimed
+    assert_eq!(cell.set_reclaiming(1), 0);
+    drop(handle);
 
-    assert_eq!(string, "Hey");
-    assert_eq!(bytes, [72, 101, 121]);
+    // Both of the old values are reclaimed
+    assert_eq!(cell.set_reclaiming(2), 2);
     ```
     */
-    pub fn read(&self) -> ReadHandle<'_, T> {
-        // Retrieve a new hazard pointer
-        let hzrd_ptr = self.domain.hzrd_ptr();
-
-        // SAFETY: The hazard pointer will protect the value
-        unsafe { ReadHandle::read_unchecked(&self.value, hzrd_ptr, Action::Release) }
+    pub fn set_reclaiming(&self, value: T) -> usize {
+        // SAFETY: We retire the pointer in a valid domain
+        let old_ptr = unsafe { self.swap(Box::new(value)) };
+        self.domain.retire(old_ptr)
     }
 
     /**
-    Read the associated value and copy it (requires the type to be [`Copy`])
+    Set the value of the cell to a clone of the given value (requires the type to be [`Clone`])
+
+    This is useful for copying the value of one cell into another, as the value can be passed directly from a [`ReadHandle`].
 
     # Example
     ```
     # use hzrd::HzrdCell;
-    let cell = HzrdCell::new(100);
-    assert_eq!(cell.get(), 100);
+    let source = HzrdCell::new(String::from("Hello"));
+    let target = HzrdCell::new(String::new());
+
+    target.set_from_ref(&source.read());
+    assert_eq!(*target.read(), "Hello");
     ```
     */
-    pub fn get(&self) -> T
+    pub fn set_from_ref(&self, value: &T)
     where
-        T: Copy,
+        T: Clone,
     {
-        *self.read()
+        self.set(T::clone(value));
     }
 
     /**
-    Reclaim available memory, if possible
+    Set the value of the cell, unless it is equal to the current value
+
+    Returns whether or not the value was written. Skipping redundant writes avoids allocating the new value, as well as retiring the old one, which reduces the pressure on reclamation when the same value is written repeatedly. The comparison and the write are done atomically using [`compare_exchange`](HzrdCell::compare_exchange), so the new value is never swapped in over an equal value.
 
     # Example
     ```
     # use hzrd::HzrdCell;
-    #
     let cell = HzrdCell::new(0);
-
-    cell.just_set(1); // Current garbage: [0]
-    cell.just_set(2); // Current garbage: [0, 1]
-    cell.reclaim(); // Current garbage: []
+    assert!(cell.set_if_changed(1));
+    assert!(!cell.set_if_changed(1));
+    # assert_eq!(cell.get(), 1);
     ```
     */
-    pub fn reclaim(&self) {
-        self.domain.reclaim();
+    pub fn set_if_changed(&self, value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut value = value;
+        loop {
+            let current = self.read();
+            if *current == value {
+                return false;
+            }
+
+            match self.compare_exchange(&current, value) {
+                Ok(()) => return true,
+                Err(new) => value = new,
+            }
+        }
     }
 
     /**
-    Construct a reader to the current cell
+    Set the value of the cell, but only if the current value is equal to the given value
 
-    Constructing a reader can be helpful (and more performant) when doing consecutive reads, as the reader will hold a [`HzrdPtr`] which will be reused for each read. The reader exposes a similar API to [`HzrdCell`], with the exception of "write-actions" such as [`HzrdCell::set`] & [`HzrdCell::reclaim`]. See [`HzrdReader`] for more details.
+    Returns whether or not the value was written. Unlike [`compare_exchange`](HzrdCell::compare_exchange), which compares the identity of the values, this compares the values themselves. If the cell is written to between the comparison and the write, the new value is compared again.
+
+    Note that the comparison is only by value: If the cell is changed from `current`, and then back to an equal value, this will still succeed. This is the "ABA problem", and means that this can not be used to detect whether the cell has been written to in the meantime.
 
     # Example
     ```
     # use hzrd::HzrdCell;
     let cell = HzrdCell::new(false);
-    let reader = cell.reader();
-    # let mut reader = reader;
-    # assert_eq!(reader.get(), false)
+    assert!(cell.compare_and_set(&false, true));
+    assert!(!cell.compare_and_set(&false, true));
+    # assert!(cell.get());
     ```
     */
-    pub fn reader(&self) -> HzrdReader<'_, T> {
-        HzrdReader {
-            value: &self.value,
-            hzrd_ptr: self.domain.hzrd_ptr(),
+    pub fn compare_and_set(&self, current: &T, new: T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut new = new;
+        loop {
+            let handle = self.read();
+            if *handle != *current {
+                return false;
+            }
+
+            match self.compare_exchange(&handle, new) {
+                Ok(()) => return true,
+                Err(value) => new = value,
+            }
         }
     }
-}
 
-impl<T: 'static, D> HzrdCell<T, D> {
     /**
-    Construct a new [`HzrdCell`] in the given domain.
-
-    This method is aimed at more advanced usage of this library, as it requires more knowledge about hazard pointer domains and how they work  The recommended way for most users to construct a [`HzrdCell`] is using the [`new`](`HzrdCell::new`) function, which uses a globally shared domain.
-
-    A good starting point for using this function is to understand the basics of the [`Domain`](`core::Domain`) trait. You can then browse the various implementations of this trait provided by this crate in the [`domains`]-module.
+    Set the value of the cell, but only if the current value satisfies the given predicate
 
-    # Note
-    The value held in the cell will be allocated on the heap via [`Box`], and is stored seperate from the metadata associated with the [`HzrdCell`].
+    Returns whether or not the value was written. The predicate is called once, with the current value. The write only happens if the value seen by the predicate is still held by the cell, which is checked by [`compare_exchange`](HzrdCell::compare_exchange). If the cell is written to after the predicate is called this returns `false`, even if the new value would also satisfy the predicate, as the predicate is not called again. Use [`fetch_update`](HzrdCell::fetch_update) to retry until the write succeeds.
 
+    # Example
     ```
-    # use hzrd::domains::SharedDomain;
     # use hzrd::HzrdCell;
-    let cell = HzrdCell::new_in(0, SharedDomain::new());
+    let cell = HzrdCell::new(5);
+    assert!(!cell.set_if(10, |&x| x > 5));
+    assert!(cell.set_if(10, |&x| x <= 5));
+    assert_eq!(cell.get(), 10);
     ```
     */
-    pub fn new_in(value: T, domain: D) -> Self {
-        let value = AtomicPtr::new(Box::into_raw(Box::new(value)));
-        Self { value, domain }
+    pub fn set_if<P: FnOnce(&T) -> bool>(&self, value: T, pred: P) -> bool {
+        let current = self.read();
+        pred(&current) && self.compare_exchange(&current, value).is_ok()
     }
 
-    /// # SAFETY
-    /// Requires correct handling of [`RetiredPtr`]
-    unsafe fn swap(&self, boxed: Box<T>) -> RetiredPtr {
-        let new_ptr = Box::into_raw(boxed);
-
-        // SAFETY: Ptr must at this point be non-null
-        let old_raw_ptr = self.value.swap(new_ptr, SeqCst);
-        let non_null_ptr = unsafe { NonNull::new_unchecked(old_raw_ptr) };
+    /**
+    Update the value of the cell with the given function, returning the previous value
 
-        // SAFETY: We can guarantee it's pointing to heap-allocated memory
-        unsafe { RetiredPtr::new(non_null_ptr) }
-    }
-}
+    This mirrors [`AtomicUsize::fetch_update`](std::sync::atomic::AtomicUsize::fetch_update). The function is called with the current value, and may return a new value to be written. If the cell is written to in the meantime the function is called again with the new value. If the function returns `None` the update is aborted, and the current value is returned as an error.
 
-impl<T, D> Drop for HzrdCell<T, D> {
-    fn drop(&mut self) {
-        // SAFETY: No more references can be held if this is being dropped
-        let _ = unsafe { Box::from_raw(self.value.load(SeqCst)) };
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(7);
+    assert_eq!(cell.fetch_update(|_| None), Err(7));
+    assert_eq!(cell.fetch_update(|x| Some(x + 1)), Ok(7));
+    assert_eq!(cell.fetch_update(|x| Some(x + 1)), Ok(8));
+    assert_eq!(cell.get(), 9);
+    ```
+    */
+    pub fn fetch_update<F>(&self, mut f: F) -> Result<T, T>
+    where
+        T: Clone,
+        F: FnMut(&T) -> Option<T>,
+    {
+        loop {
+            let handle = self.read();
+            let Some(new) = f(&handle) else {
+                return Err(T::clone(&handle));
+            };
+
+            if self.compare_exchange(&handle, new).is_ok() {
+                return Ok(T::clone(&handle));
+            }
+        }
     }
-}
 
-// SAFETY: Both the type held and the domain need to be `Send`
-unsafe impl<T: Send, D: Send> Send for HzrdCell<T, D> {}
+    /**
+    Set the value of the cell to each of the given values in turn, leaving it with the last one
 
-// SAFETY: This may be somewhat defensive?
-unsafe impl<T: Send + Sync, D: Send + Sync> Sync for HzrdCell<T, D> {}
+    All the values swapped out are retired using [`batch_retire`](`Domain::batch_retire`), which means memory is only reclaimed once, at the end. This is cheaper than calling [`set`](HzrdCell::set) for each value. Readers may observe any of the values in between.
 
-// ------------------------------
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    cell.set_many(1..=10);
+    assert_eq!(cell.get(), 10);
+    ```
+    */
+    pub fn set_many<I>(&self, values: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // SAFETY: We retire the pointers in a valid domain
+        let old_ptrs = values
+            .into_iter()
+            .map(|value| unsafe { self.swap(Box::new(value)) });
+        self.domain.batch_retire(old_ptrs);
+    }
 
-/**
-A reader object for a specific [`HzrdCell`]
+    /// Set the value of the cell without attempting to reclaim memory
+    pub fn just_set(&self, value: T) {
+        // SAFETY: We retire the pointer in a valid domain
+        let old_ptr = unsafe { self.swap(Box::new(value)) };
+        self.domain.just_retire(old_ptr);
+    }
 
-The [`HzrdReader`] holds a reference to the value of the [`HzrdCell`], as well as a [`HzrdPtr`] to read from it. When performing many, consecutive reads of a cell this can be much more performant, as you only need to retrieve the [`HzrdPtr`] once.
+    /**
+    Set the value of the cell to an already boxed value
 
-# Basic usage
-The basics of using a [`HzrdReader`] is to first create a [`HzrdCell`], and then call the [`reader`](`HzrdCell::reader`) method on the cell to retrieve a reader.
+    The box is used directly as the allocation of the new value. Like [`set`](HzrdCell::set), this will attempt to reclaim memory.
 
-```
-# use std::time::Duration;
-#
-# use hzrd::HzrdCell;
-#
-let cell = HzrdCell::new(false);
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    cell.set_boxed(Box::new(1));
+    # assert_eq!(cell.get(), 1);
+    ```
+    */
+    pub fn set_boxed(&self, boxed: Box<T>) {
+        // SAFETY: We retire the pointer in a valid domain
+        let old_ptr = unsafe { self.swap(boxed) };
+        self.domain.retire(old_ptr);
+    }
 
-std::thread::scope(|s| {
-    s.spawn(|| {
-        let mut reader = cell.reader();
-        while !reader.get() {
-            std::hint::spin_loop();
-        }
-        println!("Done!");
-    });
+    /**
+    Set the value of the cell to an already boxed value, without attempting to reclaim memory
 
-    s.spawn(|| {
-        std::thread::sleep(Duration::from_millis(1));
-        cell.set(true);
-    });
-});
-```
+    The box is used directly as the allocation of the new value. This can be used together with [`RecyclingDomain`](`crate::domains::RecyclingDomain`) to reuse allocations, as the old values are kept around until they are reclaimed into a pool.
 
-# The elephant in the room
-The keen eye might have observed some of the "funkiness" with [`HzrdReader`] in the previous example: Reading the value requires a mutable/exclusive reference, and thus the reader must be marked as `mut`. Note also that this was not the case for reading, or even writing (!), to the cell itself. Exclusivity is usually associated with mutation, but for the [`HzrdReader`] this is not the case. The reason that reading via a [`HzrdReader`] requires a mutable/exclusive reference is that the reader holds access to only a single hazard pointer. This hazard pointer is used when you read a value, so as long as you hold on the value read that hazard pointer is busy.
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    cell.just_set_boxed(Box::new(1));
+    # assert_eq!(cell.get(), 1);
+    ```
+    */
+    pub fn just_set_boxed(&self, boxed: Box<T>) {
+        // SAFETY: We retire the pointer in a valid domain
+        let old_ptr = unsafe { self.swap(boxed) };
+        self.domain.just_retire(old_ptr);
+    }
 
-```
-# use hzrd::{HzrdCell, HzrdReader};
-#
-let cell = HzrdCell::new([0, 1, 2]);
+    /**
+    Set the value of the cell to the value returned by the given closure
 
-// NOTE: The reader must be marked as mutable
-let mut reader = cell.reader();
+    This is the same as [`set`](HzrdCell::set), but the value is constructed directly in the new allocation with `Box::new(f())`, instead of being passed through the stack first. This allows the compiler to avoid copying large values from the stack to the heap, although this is an optimization, and not something which is guaranteed. The closure is called before the old value is swapped out.
 
-// NOTE: We use associated function syntax here to
-//       emphasize the need for a mutable reference
-let handle = HzrdReader::read(&mut reader);
-assert_eq!(handle[0], 0);
-```
-*/
-pub struct HzrdReader<'cell, T> {
-    value: &'cell AtomicPtr<T>,
-    hzrd_ptr: &'cell HzrdPtr,
-}
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new([0_u8; 4096]);
+    cell.set_with(|| [1_u8; 4096]);
+    assert!(cell.read().iter().all(|&byte| byte == 1));
+    ```
+    */
+    pub fn set_with<F: FnOnce() -> T>(&self, f: F) {
+        // SAFETY: We retire the pointer in a valid domain
+        let old_ptr = unsafe { self.swap(Box::new(f())) };
+        self.domain.retire(old_ptr);
+    }
 
-impl<T> HzrdReader<'_, T> {
     /**
-    Read the associated value and return a handle holding a reference it
+    Set the value of the cell to the value behind a raw pointer, taking ownership of it
 
-    Note that the reference held by the returned handle is to the value as it was when it was read.
-    If the cell is written to during the lifetime of the handle this will not be reflected in its value.
+    The old value is retired, and memory is reclaimed if possible, just like with [`set`](HzrdCell::set). This allows values which are built elsewhere, and handed over as raw pointers, to be stored without copying them.
+
+    # Safety
+    - The pointer must point to a valid value allocated as by [`Box`], e.g. with [`Box::into_raw`], using the global allocator
+    - Ownership of the value is transferred to the cell, and so the pointer must not be used, or freed, by the caller afterwards
 
     # Example
     ```
+    # use std::ptr::NonNull;
     # use hzrd::HzrdCell;
     let cell = HzrdCell::new(String::new());
-    let mut reader = cell.reader();
-    let string = reader.read();
-    assert!(string.is_empty());
+
+    let ptr = Box::into_raw(Box::new(String::from("Hello")));
+    unsafe { cell.store_raw(NonNull::new(ptr).unwrap()) };
+    assert_eq!(*cell.read(), "Hello");
     ```
     */
-    pub fn read(&mut self) -> ReadHandle<'_, T> {
-        // SAFETY: The hazard pointer will protect the value
-        unsafe { ReadHandle::read_unchecked(self.value, self.hzrd_ptr, Action::Reset) }
+    pub unsafe fn store_raw(&self, ptr: NonNull<T>) {
+        // SAFETY: The caller guarantees that the pointer is a valid, owned box
+        let boxed = unsafe { Box::from_raw(ptr.as_ptr()) };
+
+        // SAFETY: We retire the pointer in a valid domain
+        let old_ptr = unsafe { self.swap(boxed) };
+        self.domain.retire(old_ptr);
     }
 
     /**
-    Read the associated value and copy it (requires the type to be [`Copy`])
+    Replace the value of the cell, returning the old value
+
+    Other readers may still be holding on to the old value, and so it can not be moved out of the cell. Instead the old value is cloned after it has been swapped out, and then retired. Since the swap happens first, the value returned is exactly the value which was replaced, even if other threads are writing to the cell at the same time.
 
     # Example
     ```
     # use hzrd::HzrdCell;
-    let cell = HzrdCell::new('z');
-    let mut reader = cell.reader();
-    assert_eq!(reader.get(), 'z');
+    let cell = HzrdCell::new(String::from("Hello"));
+    assert_eq!(cell.replace(String::from("World")), "Hello");
+    assert_eq!(cell.replace(String::new()), "World");
     ```
     */
-    pub fn get(&mut self) -> T
+    pub fn replace(&self, value: T) -> T
+    where
+        T: Clone,
+    {
+        let old_ptr = self.swap_raw(Box::new(value));
+
+        // SAFETY: The old value is not freed before it is retired
+        let old_value = T::clone(unsafe { old_ptr.as_ref() });
+
+        // SAFETY: We can guarantee it's pointing to heap-allocated memory
+        self.domain.retire(unsafe { RetiredPtr::new(old_ptr) });
+        old_value
+    }
+
+    /**
+    Take the value of the cell, leaving [`Default::default`] in its place
+
+    See [`replace`](HzrdCell::replace) for details on how the old value is returned.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(vec![1, 2, 3]);
+    let handle = cell.read();
+
+    assert_eq!(cell.take(), [1, 2, 3]);
+    assert!(cell.read().is_empty());
+
+    // The handle still holds the old value
+    assert_eq!(*handle, [1, 2, 3]);
+    ```
+    */
+    pub fn take(&self) -> T
+    where
+        T: Clone + Default,
+    {
+        self.replace(T::default())
+    }
+
+    /**
+    Exchange the values of two cells in the same domain
+
+    The allocations are simply exchanged, so no values are retired. This can be used for double-buffering, where one cell is read by others while the other is prepared.
+
+    Both cells may be read while their values are exchanged, but the exchange is not atomic: The other cell is swapped first, and this cell second. In between, a reader may observe both cells holding the same value, i.e. the old value of this cell. Readers still holding the previous value of either cell keep it protected, even after it has been moved into the other cell.
+
+    # Panics
+    Values protected by hazard pointers in one domain can not be retired to another domain, and so this panics if the cells are in different domains. This includes domains without an id (see [`Domain::domain_id`]), as they can not be told apart.
+
+    # Safety
+    Neither cell may be written to, or swapped with another cell, at the same time. A write would retire its old value, which may at that point still be held by the other cell.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let front = HzrdCell::new(vec![1, 2, 3]);
+    let back = HzrdCell::new(Vec::new());
+
+    back.set(vec![4, 5, 6]);
+    unsafe { back.swap_cell(&front) };
+
+    assert_eq!(*front.read(), [4, 5, 6]);
+    assert_eq!(*back.read(), [1, 2, 3]);
+    ```
+    */
+    pub unsafe fn swap_cell(&self, other: &HzrdCell<T, D>) {
+        let (id, other_id) = (self.domain.domain_id(), other.domain.domain_id());
+        assert!(
+            id != 0 && id == other_id,
+            "Can not swap values between cells in domain {id} and domain {other_id}"
+        );
+
+        // The value of this cell is unchanged until it's swapped, as the caller excludes writers
+        let value = self.value.load(SeqCst);
+
+        // Both cells now hold the same value, until the second swap
+        let other_value = other.value.swap(value, SeqCst);
+        self.value.swap(other_value, SeqCst);
+
+        #[cfg(feature = "async")]
+        {
+            self.wakers.wake_all();
+            other.wakers.wake_all();
+        }
+    }
+
+    /**
+    Get a handle holding a reference to the current value held by the [`HzrdCell`]
+
+    The functionality of this is somewhat similar to a [`MutexGuard`](std::sync::MutexGuard), except the [`ReadHandle`] only accepts reading the value. There is no locking mechanism needed to grab this handle, although there might be a short wait if the read overlaps with a write.
+
+    The [`ReadHandle`] acquired holds a shared reference to the value of the [`HzrdCell`] as it was when the [`read`](Self::read) function was called. If the value of the [`HzrdCell`] is changed after the [`ReadHandle`] is acquired its new value is not reflected in the value of the [`ReadHandle`]. The hazard pointer held by the handle will keep the old value alive. See the documentation of [`ReadHandle`] for more information.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    #
+    let cell = HzrdCell::new(String::from("Hey"));
+
+    let handle = cell.read();
+
+    // We can create multiple references from a single handle
+    let string: &str = &*handle;
+    let bytes: &[u8] = handle.as_bytes();
+
+    assert_eq!(string, "Hey");
+    assert_eq!(bytes, [72, 101, 121]);
+    ```
+    */
+    pub fn read(&self) -> ReadHandle<'_, T> {
+        // Retrieve a new hazard pointer
+        let hzrd_ptr = self.domain.hzrd_ptr();
+
+        // SAFETY: The hazard pointer will protect the value
+        unsafe {
+            ReadHandle::read_unchecked_in(&self.value, hzrd_ptr, Action::Release, &self.domain)
+        }
+    }
+
+    /**
+    Read the current value, and return the handle together with the pointer to the protected value
+
+    The pointer is the exact allocation protected by the handle, which is the same pointer the cell held when it was read. This is useful for algorithms which need to compare the identity of the value against other atomics, e.g. to detect ABA. The pointer is only guaranteed to point to a live value for as long as the handle is held, and it must never be used to mutate the value.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    let (handle, ptr) = cell.read_pinned();
+    assert_eq!(cell.as_ptr(), ptr.as_ptr());
+
+    cell.set(1);
+    assert_ne!(cell.as_ptr(), ptr.as_ptr());
+    assert_eq!(*handle, 0);
+    ```
+    */
+    pub fn read_pinned(&self) -> (ReadHandle<'_, T>, NonNull<T>) {
+        let handle = self.read();
+        let ptr = NonNull::from(&*handle);
+        (handle, ptr)
+    }
+
+    /**
+    Try to get a second handle to the value protected by the given handle
+
+    The new handle protects the value with a hazard pointer of its own, and so the two handles can be dropped independently. A hazard pointer can however only take over the protection of a value while the value is still held by the cell: A writer reclaiming memory at the same time could otherwise see neither hazard pointer while the protection is handed over, and free the value. The new hazard pointer is therefore verified against the cell just like a regular read, and this returns `None` if the cell has been written to since the given handle was read. This also returns `None` if the handle was read from another cell, or if no hazard pointer is available (see [`try_read`](HzrdCell::try_read)).
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::from("Hello"));
+
+    let handle = cell.read();
+    let clone = cell.try_clone_handle(&handle).unwrap();
+    drop(handle);
+    assert_eq!(*clone, "Hello");
+
+    cell.set(String::from("World"));
+    assert!(cell.try_clone_handle(&clone).is_none());
+    ```
+    */
+    pub fn try_clone_handle(&self, handle: &ReadHandle<'_, T>) -> Option<ReadHandle<'_, T>> {
+        let clone = self.try_read()?;
+        (clone.as_ptr() == handle.as_ptr()).then_some(clone)
+    }
+
+    /**
+    Try to get a handle holding a reference to the current value held by the [`HzrdCell`], without waiting
+
+    This works like [`read`](Self::read), but makes a single attempt at protecting the value. [`read`](Self::read) keeps retrying until the value is stable, whereas this returns `None` if the cell was written to while the value was being protected. This is useful for best-effort reads in busy loops, where the caller would rather do other work than wait for writers.
+
+    This also returns `None` if the domain has no hazard pointer available. This can only happen for domains with a bounded number of hazard pointers, such as [`FixedDomain`](crate::domains::FixedDomain), see [`Domain::try_hzrd_ptr`].
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::from("Hey"));
+    if let Some(handle) = cell.try_read() {
+        assert_eq!(*handle, "Hey");
+    };
+    ```
+    */
+    pub fn try_read(&self) -> Option<ReadHandle<'_, T>> {
+        let hzrd_ptr = self.domain.try_hzrd_ptr()?;
+        hzrd_ptr.debug_assert_domain(&self.domain);
+
+        // SAFETY: The hazard pointer will protect the value, and is released if the read fails
+        unsafe { ReadHandle::try_read_unchecked(&self.value, hzrd_ptr, Action::Release, 1) }
+    }
+
+    /**
+    Protect the current value with the given hazard pointer, and return a reference to it
+
+    This is a low-level alternative to [`read`](Self::read) for custom data structures, which manage their own hazard pointers. The reference is valid for as long as the hazard pointer protects the value, and there is no handle which resets or releases the hazard pointer once it's done. See [`HzrdPtr::load_protected`] for more details.
+
+    # Panics
+    In debug builds, this panics if the hazard pointer belongs to a different domain than the cell.
+
+    # Safety
+    - The caller must be the current "owner" of the hazard pointer
+    - The hazard pointer must belong to the domain of the cell
+    - The hazard pointer must not be reset, released or used to protect another value while the reference is in use
+
+    # Example
+    ```
+    # use hzrd::core::Domain;
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::HzrdCell;
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+
+    let hzrd_ptr = domain.hzrd_ptr();
+    let string = unsafe { cell.protect_with(hzrd_ptr) };
+    cell.set(String::from("World"));
+    assert_eq!(string, "Hello");
+
+    // The old value can be reclaimed once the hazard pointer is released
+    unsafe { hzrd_ptr.release() };
+    ```
+    */
+    pub unsafe fn protect_with<'t>(&'t self, hzrd_ptr: &'t HzrdPtr) -> &'t T {
+        hzrd_ptr.debug_assert_domain(&self.domain);
+
+        // SAFETY: Same requirements as this function
+        unsafe { hzrd_ptr.load_protected(&self.value) }
+    }
+
+    /**
+    Read the associated value and copy it (requires the type to be [`Copy`])
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(100);
+    assert_eq!(cell.get(), 100);
+    ```
+    */
+    pub fn get(&self) -> T
     where
         T: Copy,
     {
         *self.read()
     }
-}
 
-impl<T> Drop for HzrdReader<'_, T> {
-    fn drop(&mut self) {
-        // SAFETY: We are the current owner of the hazard pointer
-        unsafe { self.hzrd_ptr.release() };
+    /**
+    Read the associated value and clone it (requires the type to be [`Clone`])
+
+    The hazard pointer is released as soon as the value is cloned.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::from("Hello"));
+    let string: String = cell.cloned();
+    assert_eq!(string, "Hello");
+    ```
+    */
+    pub fn cloned(&self) -> T
+    where
+        T: Clone,
+    {
+        T::clone(&self.read())
+    }
+
+    /**
+    Read the associated value and apply the given closure to it, returning the result
+
+    The value is protected by a hazard pointer while the closure runs, and the hazard pointer is released as soon as the closure returns. This avoids holding on to a [`ReadHandle`] (and thereby keeping the value from being reclaimed) for longer than needed.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::from("Hello"));
+    let len = cell.read_and(|string| string.len());
+    assert_eq!(len, 5);
+    ```
+    */
+    pub fn read_and<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        f(&self.read())
+    }
+
+    /**
+    Read the associated value and clone it into a new [`Box`] (requires the type to be [`Clone`])
+
+    This is useful for handing the value over to an API that wants ownership of a boxed value.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    fn takes_box(boxed: Box<Vec<i32>>) -> usize {
+        boxed.len()
+    }
+
+    let cell = HzrdCell::new(vec![1, 2, 3]);
+    assert_eq!(takes_box(cell.read_boxed()), 3);
+    ```
+    */
+    pub fn read_boxed(&self) -> Box<T>
+    where
+        T: Clone,
+    {
+        Box::new(T::clone(&self.read()))
+    }
+
+    /**
+    Read the associated value and clone it into a new [`Arc`] (requires the type to be [`Clone`])
+
+    The hazard pointer is only held while cloning, and is released before returning. The returned snapshot can therefore be shared and held for as long as needed, without preventing the old value from being reclaimed after the cell is updated.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::from("config"));
+    let snapshot = cell.read_arc();
+
+    cell.set(String::from("new config"));
+    assert_eq!(*snapshot, "config");
+    ```
+    */
+    pub fn read_arc(&self) -> Arc<T>
+    where
+        T: Clone,
+    {
+        Arc::new(T::clone(&self.read()))
+    }
+
+    /**
+    Construct a new cell holding a value derived from the current value of this cell
+
+    The new cell is completely independent of this one, it holds a snapshot of the derived value as it was when this cell was read. Later writes to this cell will not be reflected in the new cell. The new cell is created in the default domain.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(vec![1, 2, 3]);
+    let len = cell.map_into(|vec| vec.len());
+    assert_eq!(len.get(), 3);
+
+    // The new cell is not updated
+    cell.set(Vec::new());
+    assert_eq!(len.get(), 3);
+    ```
+    */
+    pub fn map_into<U: 'static, F: FnOnce(&T) -> U>(&self, f: F) -> HzrdCell<U> {
+        HzrdCell::new(f(&self.read()))
+    }
+
+    /**
+    Reclaim available memory, if possible
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    #
+    let cell = HzrdCell::new(0);
+
+    cell.just_set(1); // Current garbage: [0]
+    cell.just_set(2); // Current garbage: [0, 1]
+    cell.reclaim(); // Current garbage: []
+    ```
+    */
+    pub fn reclaim(&self) {
+        self.domain.reclaim();
+    }
+
+    /**
+    Reclaim available memory, regardless of the configured bulk size
+
+    See [`Domain::reclaim_all`] for more details.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    cell.just_set(1);
+    cell.reclaim_all();
+    ```
+    */
+    pub fn reclaim_all(&self) {
+        self.domain.reclaim_all();
+    }
+
+    /**
+    Get the number of readers currently protecting the value of the cell
+
+    This scans the hazard pointers of the domain for ones protecting the current value, see [`Domain::protection_count`]. Readers of older values are not counted. The count is only a snapshot, as new readers may arrive at any time, but it gives a cheap way to check if anyone is reading right now. Domains which don't know their hazard pointers always report zero.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    assert_eq!(cell.active_readers(), 0);
+
+    let handle_1 = cell.read();
+    let handle_2 = cell.read();
+    assert_eq!(cell.active_readers(), 2);
+
+    // The handles protect the old value
+    cell.set(1);
+    assert_eq!(cell.active_readers(), 0);
+    # drop((handle_1, handle_2));
+    ```
+    */
+    pub fn active_readers(&self) -> usize {
+        self.domain.protection_count(self.as_ptr() as usize)
+    }
+
+    /**
+    Construct a reader to the current cell
+
+    Constructing a reader can be helpful (and more performant) when doing consecutive reads, as the reader will hold a [`HzrdPtr`] which will be reused for each read. The reader exposes a similar API to [`HzrdCell`], with the exception of "write-actions" such as [`HzrdCell::set`] & [`HzrdCell::reclaim`]. See [`HzrdReader`] for more details.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(false);
+    let reader = cell.reader();
+    # let mut reader = reader;
+    # assert_eq!(reader.get(), false)
+    ```
+    */
+    pub fn reader(&self) -> HzrdReader<'_, T, D> {
+        HzrdReader {
+            value: &self.value,
+            hzrd_ptr: self.domain.hzrd_ptr(),
+            domain: &self.domain,
+            pool: SharedStack::new(),
+        }
+    }
+
+    /**
+    Construct an observer of the current cell, which only reads the value if it has changed
+
+    See [`Observer`] for more details.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    let mut observer = cell.observe();
+    assert_eq!(observer.changed().as_deref(), Some(&0));
+    assert_eq!(observer.changed().as_deref(), None);
+    ```
+    */
+    pub fn observe(&self) -> Observer<'_, T> {
+        Observer {
+            value: &self.value,
+            hzrd_ptr: self.domain.hzrd_ptr(),
+            last: 0,
+        }
+    }
+
+    /**
+    Run the given closure with a reader to the current cell, dropping the reader afterwards
+
+    This makes sure the hazard pointer of the reader is released as soon as the closure returns, which keeps the reader short-lived. See [`reader`](HzrdCell::reader) for more details.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(1);
+    let sum: i32 = cell.with_reader(|reader| (0..10).map(|_| reader.get()).sum());
+    assert_eq!(sum, 10);
+    ```
+    */
+    pub fn with_reader<R>(&self, f: impl FnOnce(&mut HzrdReader<'_, T, D>) -> R) -> R {
+        f(&mut self.reader())
+    }
+
+    /**
+    Construct a reader to the current cell which does not borrow the cell
+
+    The cell must be held in an [`Arc`], and the returned [`OwnedReader`] holds a clone of it. This keeps both the value and the domain alive for as long as the reader exists, so the reader is `'static` if the value and the domain are. This makes it possible to move the reader into a detached thread, unlike the [`HzrdReader`].
+
+    # Example
+    ```
+    use std::sync::Arc;
+
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let cell = Arc::new(HzrdCell::new_in(0, Arc::new(SharedDomain::new())));
+    let mut reader = cell.owned_reader();
+
+    let thread = std::thread::spawn(move || reader.get());
+    assert_eq!(thread.join().unwrap(), 0);
+    ```
+    */
+    pub fn owned_reader(self: &Arc<Self>) -> OwnedReader<T, D> {
+        let hzrd_ptr = NonNull::from(self.domain.hzrd_ptr());
+        OwnedReader {
+            cell: Arc::clone(self),
+            hzrd_ptr,
+        }
+    }
+
+    /**
+    Attempt to read the value of the cell without blocking
+
+    The read is attempted with [`try_read`](HzrdCell::try_read). If it fails, the waker of the given context is registered and [`Poll::Pending`] is returned. The waker is woken on the next write to the cell. This is meant as a building block for custom futures, e.g. with [`poll_fn`](std::future::poll_fn).
+
+    Note that [`try_read`](HzrdCell::try_read) also fails if the domain has no hazard pointer available, such as an exhausted [`FixedDomain`](crate::domains::FixedDomain). Releasing a hazard pointer does not wake the waker, and so the task is only polled again on the next write.
+
+    Requires the `async` feature.
+
+    # Example
+    ```
+    use std::future::{poll_fn, Future};
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use hzrd::HzrdCell;
+
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let cell = HzrdCell::new(String::from("Hello"));
+    let mut future = pin!(poll_fn(|cx| cell.poll_read(cx).map(|handle| handle.len())));
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(5));
+    ```
+    */
+    #[cfg(feature = "async")]
+    pub fn poll_read(&self, cx: &mut Context<'_>) -> Poll<ReadHandle<'_, T>> {
+        if let Some(handle) = self.try_read() {
+            return Poll::Ready(handle);
+        }
+
+        // Register before the second attempt, so that a write in between is not missed
+        self.wakers.register(cx.waker());
+        match self.try_read() {
+            Some(handle) => Poll::Ready(handle),
+            None => Poll::Pending,
+        }
+    }
+
+    /**
+    Wait until the value of the cell satisfies the given predicate, and return a handle holding it
+
+    The predicate is checked against the current value, and if it's not satisfied the task is parked until the next write to the cell. No thread is kept spinning while waiting, so this is meant as an alternative to spin-looping on [`read`](HzrdCell::read) in async contexts. The future is runtime-agnostic, it only relies on the waker of the task.
+
+    Requires the `async` feature.
+
+    # Example
+    ```
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use hzrd::HzrdCell;
+
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let cell = HzrdCell::new(0);
+    let mut future = pin!(cell.wait_until(|value| *value == 1));
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+
+    cell.set(1);
+    assert!(matches!(future.as_mut().poll(&mut cx), Poll::Ready(handle) if *handle == 1));
+    ```
+    */
+    #[cfg(feature = "async")]
+    pub async fn wait_until<F: Fn(&T) -> bool>(&self, pred: F) -> ReadHandle<'_, T> {
+        ::core::future::poll_fn(|cx| {
+            let handle = self.read();
+            if pred(&handle) {
+                return Poll::Ready(handle);
+            }
+            drop(handle);
+
+            // Register before checking again, so that a write in between is not missed
+            self.wakers.register(cx.waker());
+            let handle = self.read();
+            match pred(&handle) {
+                true => Poll::Ready(handle),
+                false => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /**
+    Try to swap in a new value, but only if the cell still holds the value protected by the given handle
+
+    The comparison is based on pointer identity, so the expected handle must be one read from this cell. If the value has changed the current value is read and returned as part of [`CasResult::Mismatch`], along with the value that was attempted written. Should the value change again while it's being read, the exchange is retried after a short backoff. Once the value has been replaced more than a few times during a single call [`CasResult::Contended`] is returned instead, signalling that the caller should back off rather than retry immediately.
+
+    # Example
+    ```
+    use hzrd::{CasResult, HzrdCell};
+
+    let cell = HzrdCell::new(0);
+
+    let handle = cell.read();
+    assert!(matches!(cell.try_compare_exchange(&handle, 1), CasResult::Succeeded));
+
+    // The handle is now outdated, the cell holds the value 1
+    match cell.try_compare_exchange(&handle, 2) {
+        CasResult::Mismatch(current, new) => assert_eq!((*current, new), (1, 2)),
+        CasResult::Contended(new) => assert_eq!(new, 2),
+        CasResult::Succeeded => unreachable!(),
+    };
+    ```
+    */
+    pub fn try_compare_exchange(&self, expected: &ReadHandle<'_, T>, new: T) -> CasResult<'_, T> {
+        let new_ptr = Box::into_raw(Box::new(new));
+
+        // SAFETY: The new value is only shared if the exchange succeeds, which ends the loop
+        let take_new = || *unsafe { Box::from_raw(new_ptr) };
+
+        // The hazard pointer is only acquired once the exchange has failed
+        let mut hzrd_ptr: Option<&HzrdPtr> = None;
+        let mut last_seen = None;
+        let mut churn = 0;
+
+        loop {
+            let current =
+                match self
+                    .value
+                    .compare_exchange(expected.as_ptr(), new_ptr, SeqCst, SeqCst)
+                {
+                    Ok(old_ptr) => {
+                        if let Some(hzrd_ptr) = hzrd_ptr {
+                            // SAFETY: We are the owner of the hazard pointer, and no handle uses it
+                            unsafe { hzrd_ptr.release() };
+                        }
+
+                        #[cfg(feature = "async")]
+                        self.wakers.wake_all();
+
+                        // SAFETY: The pointer was held by the cell, so it's non-null and heap-allocated
+                        let old_ptr = unsafe { RetiredPtr::new(NonNull::new_unchecked(old_ptr)) };
+                        self.domain.retire(old_ptr);
+                        return CasResult::Succeeded;
+                    }
+                    // SAFETY: The cell never holds a null pointer
+                    Err(current) => unsafe { NonNull::new_unchecked(current) },
+                };
+
+            // The value was replaced during the backoff
+            if last_seen.is_some_and(|ptr| ptr != current) {
+                churn += 1;
+            }
+
+            let hzrd_ptr = *hzrd_ptr.get_or_insert_with(|| self.domain.hzrd_ptr());
+            if churn > CONTENTION_LIMIT {
+                // SAFETY: We are the owner of the hazard pointer, and no handle uses it
+                unsafe { hzrd_ptr.release() };
+                return CasResult::Contended(take_new());
+            }
+
+            // SAFETY: The hazard pointer is only used for this value
+            match unsafe {
+                ReadHandle::try_protect_unchecked(&self.value, hzrd_ptr, current, Action::Release)
+            } {
+                Ok(current) => return CasResult::Mismatch(current, take_new()),
+                // The value was replaced while it was being protected
+                Err(new_ptr) => {
+                    churn += 1;
+                    last_seen = Some(new_ptr);
+                }
+            }
+
+            #[cfg(feature = "std")]
+            std::thread::yield_now();
+            #[cfg(not(feature = "std"))]
+            ::core::hint::spin_loop();
+        }
+    }
+
+    /**
+    Swap in a new value, but only if the cell still holds the value protected by the given handle
+
+    The comparison is based on pointer identity, so the expected handle must be one read from this cell. On success the old value is retired. On failure the new value is handed back, so that nothing is lost. This can be used to build custom retry loops:
+
+    # Example
+    ```
+    use hzrd::HzrdCell;
+
+    let cell = HzrdCell::new(vec![1, 2, 3]);
+
+    // Push a value, retrying until no other write happened in between
+    loop {
+        let current = cell.read();
+        let mut values = current.clone();
+        values.push(4);
+
+        if cell.compare_exchange(&current, values).is_ok() {
+            break;
+        }
+    }
+
+    assert_eq!(cell.read().as_slice(), [1, 2, 3, 4]);
+    ```
+    */
+    pub fn compare_exchange(&self, expected: &ReadHandle<'_, T>, new: T) -> Result<(), T> {
+        let new_ptr = Box::into_raw(Box::new(new));
+        match self
+            .value
+            .compare_exchange(expected.as_ptr(), new_ptr, SeqCst, SeqCst)
+        {
+            Ok(old_ptr) => {
+                #[cfg(feature = "async")]
+                self.wakers.wake_all();
+
+                // SAFETY: The pointer was held by the cell, so it's non-null and heap-allocated
+                let old_ptr = unsafe { RetiredPtr::new(NonNull::new_unchecked(old_ptr)) };
+                self.domain.retire(old_ptr);
+                Ok(())
+            }
+            Err(_) => {
+                // SAFETY: The new value was never shared, so we still have exclusive ownership
+                Err(*unsafe { Box::from_raw(new_ptr) })
+            }
+        }
+    }
+}
+
+impl<T: 'static, D> HzrdCell<T, D> {
+    /**
+    Construct a new [`HzrdCell`] in the given domain.
+
+    This method is aimed at more advanced usage of this library, as it requires more knowledge about hazard pointer domains and how they work  The recommended way for most users to construct a [`HzrdCell`] is using the [`new`](`HzrdCell::new`) function, which uses a globally shared domain.
+
+    A good starting point for using this function is to understand the basics of the [`Domain`](`core::Domain`) trait. You can then browse the various implementations of this trait provided by this crate in the [`domains`]-module.
+
+    # Note
+    The value held in the cell will be allocated on the heap via [`Box`], and is stored seperate from the metadata associated with the [`HzrdCell`].
+
+    ```
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new_in(0, SharedDomain::new());
+    ```
+    */
+    pub fn new_in(value: T, domain: D) -> Self {
+        Self::new_boxed_in(Box::new(value), domain)
+    }
+
+    /**
+    Construct a new [`HzrdCell`] from an already boxed value in the given domain.
+
+    The box is used directly as the allocation of the value, see [`new_boxed`](`HzrdCell::new_boxed`) for more details.
+
+    ```
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new_boxed_in(Box::new(0), SharedDomain::new());
+    # assert_eq!(cell.get(), 0);
+    ```
+    */
+    pub fn new_boxed_in(boxed: Box<T>, domain: D) -> Self {
+        let value = AtomicPtr::new(Box::into_raw(boxed));
+        Self {
+            value,
+            domain,
+            #[cfg(feature = "async")]
+            wakers: wakers::WakerRegistry::default(),
+        }
+    }
+
+    /**
+    Try to construct a new [`HzrdCell`] with the given value in the given domain, returning the value if allocation fails
+
+    Unlike [`HzrdCell::new_in`], which aborts if the value can not be allocated, this hands the value back to the caller so that it can degrade gracefully. Only the construction of the cell is fallible: Writing to the cell, and retiring values to the domain, will still abort on allocation failure.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    # use hzrd::domains::SharedDomain;
+    match HzrdCell::try_new_in(vec![0; 1024], SharedDomain::new()) {
+        Ok(cell) => assert_eq!(cell.read().len(), 1024),
+        // The value is handed back, and can e.g. be dropped to free memory
+        Err(value) => drop(value),
+    }
+    ```
+    */
+    pub fn try_new_in(value: T, domain: D) -> Result<Self, T> {
+        try_box(value).map(|boxed| Self::new_boxed_in(boxed, domain))
+    }
+
+    /**
+    Get a reference to the domain of the cell
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::HzrdCell;
+    let domain = Arc::new(SharedDomain::new());
+    let cell = HzrdCell::new_in(0, Arc::clone(&domain));
+    assert!(Arc::ptr_eq(cell.domain(), &domain));
+    ```
+    */
+    pub fn domain(&self) -> &D {
+        &self.domain
+    }
+
+    /**
+    Get a raw pointer to the current value of the cell
+
+    This is meant for interop and debugging, e.g. for comparing the identity of values, or for inspecting the cell in a debugger. The pointer is **not** protected by any hazard pointer: The value can be retired, and freed, at any point after the pointer is loaded. Dereferencing the pointer is therefore only sound if the caller can guarantee that the cell is not written to, and that memory is not reclaimed, for as long as the reference is in use. Use [`read`](HzrdCell::read) to get a protected reference.
+
+    The value must never be mutated through the pointer, as readers may hold shared references to it.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    let handle = cell.read();
+    assert_eq!(cell.as_ptr().cast_const(), &*handle as *const i32);
+
+    cell.set(1);
+    assert_ne!(cell.as_ptr().cast_const(), &*handle as *const i32);
+    ```
+    */
+    pub fn as_ptr(&self) -> *mut T {
+        self.value.load(SeqCst)
+    }
+
+    /**
+    Consume the cell, and return the value it holds
+
+    No reader can exist as the cell is taken by value, and so the value is moved out without being cloned. Older values which are still retired are left to the domain.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(vec![1, 2, 3]);
+    cell.set(vec![4, 5, 6]);
+    assert_eq!(cell.into_inner(), [4, 5, 6]);
+    ```
+    */
+    pub fn into_inner(self) -> T {
+        // The cell is left with a null pointer, which is skipped when it's dropped
+        let ptr = self.value.swap(::core::ptr::null_mut(), SeqCst);
+
+        // SAFETY: No more references can be held as we own the cell, and the pointer is never null before this
+        *unsafe { Box::from_raw(ptr) }
+    }
+
+    /**
+    Get a mutable reference to the current value
+
+    The cell is borrowed mutably, and so there can be no readers, and no other writers, while the reference is in use. The value is accessed directly, without using a hazard pointer.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let mut cell = HzrdCell::new(vec![1, 2, 3]);
+    cell.get_mut().push(4);
+    assert_eq!(cell.read()[..], [1, 2, 3, 4]);
+    ```
+    */
+    pub fn get_mut(&mut self) -> &mut T {
+        // SAFETY: The pointer is never null, and no reference to the current value can be held
+        unsafe { &mut *self.value.load(SeqCst) }
+    }
+
+    /// # SAFETY
+    /// Requires correct handling of [`RetiredPtr`]
+    unsafe fn swap(&self, boxed: Box<T>) -> RetiredPtr {
+        let non_null_ptr = self.swap_raw(boxed);
+
+        // SAFETY: We can guarantee it's pointing to heap-allocated memory
+        unsafe { RetiredPtr::new(non_null_ptr) }
+    }
+
+    /// Swap in the new value, returning the pointer to the old value
+    ///
+    /// The old value may still be protected by hazard pointers, and must be retired
+    fn swap_raw(&self, boxed: Box<T>) -> NonNull<T> {
+        let new_ptr = Box::into_raw(boxed);
+
+        // SAFETY: Ptr must at this point be non-null
+        let old_raw_ptr = self.value.swap(new_ptr, SeqCst);
+        let non_null_ptr = unsafe { NonNull::new_unchecked(old_raw_ptr) };
+
+        #[cfg(feature = "async")]
+        self.wakers.wake_all();
+
+        non_null_ptr
+    }
+}
+
+impl<T: Clone + 'static, D: Domain> HzrdCell<Option<T>, D> {
+    /**
+    Take the value out of the cell if there is one, leaving [`None`] in its place
+
+    This uses [`compare_exchange`](HzrdCell::compare_exchange), such that if multiple threads race to take the value only one of them will get it, the others get [`None`]. This makes the cell usable as a one-shot slot with multiple consumers. Other readers may still be holding on to the old value, and so the inner value is cloned while it's protected, before the cell is emptied.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(Some(String::from("Hello")));
+    assert_eq!(cell.take_if_some().as_deref(), Some("Hello"));
+    assert_eq!(cell.take_if_some(), None);
+    ```
+    */
+    pub fn take_if_some(&self) -> Option<T> {
+        let mut empty = None;
+        loop {
+            let current = self.read();
+            let value = current.as_ref()?.clone();
+
+            match self.compare_exchange(&current, empty) {
+                Ok(()) => return Some(value),
+                Err(none) => empty = none,
+            }
+        }
+    }
+
+    /**
+    Get the inner value if there is one, otherwise initialize the cell with the value returned by the given function
+
+    The function is called at most once, and the new value is stored using [`compare_exchange`](HzrdCell::compare_exchange). If another thread initializes the cell first, the value stored by that thread is returned instead, and the value returned by the function is dropped. This means all threads agree on the value of the cell once it has been initialized.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(None);
+    assert_eq!(cell.get_or_set_with(|| String::from("Hello")), "Hello");
+    assert_eq!(cell.get_or_set_with(|| String::from("World")), "Hello");
+    ```
+    */
+    pub fn get_or_set_with<F: FnOnce() -> T>(&self, f: F) -> T {
+        let mut f = Some(f);
+        let mut new = None;
+        loop {
+            let current = self.read();
+            if let Some(value) = current.as_ref() {
+                return value.clone();
+            }
+
+            // Only compute the value once, it's handed back if the exchange fails
+            let value = match new.take() {
+                Some(value) => value,
+                None => f.take().expect("function is only called once")(),
+            };
+
+            match self.compare_exchange(&current, Some(value.clone())) {
+                Ok(()) => return value,
+                Err(value) => new = value,
+            }
+        }
+    }
+}
+
+impl<T: 'static, D: Domain> HzrdCell<Vec<T>, D> {
+    /**
+    Get a clone of the element at the given index, or `None` if the index is out of bounds
+
+    Only the element is cloned, and the hazard pointer is released before returning. This avoids holding on to the whole vector when only a single element is needed.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(vec![String::from("Hello"), String::from("World")]);
+    assert_eq!(cell.get_index(1).as_deref(), Some("World"));
+    assert_eq!(cell.get_index(2), None);
+    ```
+    */
+    pub fn get_index(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.read().get(index).cloned()
+    }
+
+    /**
+    Get the number of elements in the vector
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(vec![1, 2, 3]);
+    assert_eq!(cell.len(), 3);
+    ```
+    */
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /**
+    Check if the vector is empty
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(Vec::<i32>::new());
+    assert!(cell.is_empty());
+    ```
+    */
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+}
+
+impl<T, D> HzrdCell<T, Arc<D>> {
+    /**
+    Get the number of strong references to the domain of the cell
+
+    This is only available for cells whose domain is held by an [`Arc`], such as when multiple cells share a [`SharedDomain`](`crate::domains::SharedDomain`). The count includes the reference held by this cell, as well as any other cells, or handles, referencing the same domain. It can be used to check how many cells are still alive before tearing down the domain.
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::HzrdCell;
+    let domain = Arc::new(SharedDomain::new());
+    let cell_1 = HzrdCell::new_in(0, Arc::clone(&domain));
+    let cell_2 = HzrdCell::new_in(1, Arc::clone(&domain));
+    assert_eq!(cell_1.domain_strong_count(), 3);
+
+    drop(cell_2);
+    assert_eq!(cell_1.domain_strong_count(), 2);
+    ```
+    */
+    pub fn domain_strong_count(&self) -> usize {
+        Arc::strong_count(&self.domain)
+    }
+}
+
+/**
+Construct a new [`HzrdCell`] holding the default value, in the default domain
+
+# Example
+```
+# use hzrd::HzrdCell;
+#[derive(Default)]
+struct Stats {
+    requests: HzrdCell<u64>,
+    last_path: HzrdCell<String>,
+}
+
+let stats = Stats::default();
+assert_eq!(stats.requests.get(), 0);
+assert_eq!(*stats.last_path.read(), "");
+```
+*/
+impl<T: Default + 'static> Default for HzrdCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/**
+Construct a new cell holding a clone of the current value
+
+Note that this clones the value, not the cell: The new cell is independent of the original, and writes to one will not be seen through the other. This is unlike e.g. [`Arc`], where clones share the same value. To share a single cell, wrap it in an [`Arc`] instead. The new cell is always created in the default domain.
+
+# Example
+```
+# use hzrd::HzrdCell;
+#[derive(Clone)]
+struct State {
+    name: HzrdCell<String>,
+}
+
+let state = State { name: HzrdCell::new(String::from("Hello")) };
+let snapshot = state.clone();
+
+state.name.set(String::from("World"));
+assert_eq!(*snapshot.name.read(), "Hello");
+```
+*/
+impl<T: Clone + 'static> Clone for HzrdCell<T> {
+    fn clone(&self) -> Self {
+        Self::new(T::clone(&self.read()))
+    }
+}
+
+/**
+Compare the current values of two cells
+
+Both cells are read, and the values are compared while they are protected. This allows for comparing cells directly, and for deriving [`PartialEq`] for types holding cells. Note that this is only a point-in-time comparison: Either cell may be written to while they are being compared, and so the result can be outdated by the time it's returned. It should not be relied upon for synchronization.
+
+# Example
+```
+# use hzrd::HzrdCell;
+#[derive(PartialEq)]
+struct State {
+    name: HzrdCell<String>,
+}
+
+let a = State { name: HzrdCell::new(String::from("Hello")) };
+let b = State { name: HzrdCell::new(String::from("Hello")) };
+assert!(a == b);
+
+b.name.set(String::from("World"));
+assert!(a != b);
+```
+*/
+impl<T: PartialEq + 'static, D: Domain> PartialEq for HzrdCell<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.read() == *other.read()
+    }
+}
+
+impl<T: 'static> From<T> for HzrdCell<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: 'static> From<Box<T>> for HzrdCell<T> {
+    fn from(boxed: Box<T>) -> Self {
+        Self::new_boxed(boxed)
+    }
+}
+
+impl<T, D> Drop for HzrdCell<T, D> {
+    fn drop(&mut self) {
+        // The value has already been moved out if the pointer is null, see `into_inner`
+        let ptr = self.value.load(SeqCst);
+        if ptr.is_null() {
+            return;
+        }
+
+        // SAFETY: No more references can be held if this is being dropped
+        let _ = unsafe { Box::from_raw(ptr) };
+    }
+}
+
+// SAFETY: Both the type held and the domain need to be `Send`
+unsafe impl<T: Send, D: Send> Send for HzrdCell<T, D> {}
+
+// SAFETY: This may be somewhat defensive?
+unsafe impl<T: Send + Sync, D: Send + Sync> Sync for HzrdCell<T, D> {}
+
+/**
+Serializes the current value of the cell (requires the `serde` feature)
+
+The value is read through [`read`](HzrdCell::read), and so it's protected for the duration of the serialization. If the cell is written to at the same time either the old or the new value is serialized, never a mix of the two.
+
+# Example
+```
+# use hzrd::HzrdCell;
+let cell = HzrdCell::new(vec![1, 2, 3]);
+let json = serde_json::to_string(&cell).unwrap();
+assert_eq!(json, "[1,2,3]");
+```
+*/
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + 'static, D: Domain> serde::Serialize for HzrdCell<T, D> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.read().serialize(serializer)
+    }
+}
+
+/**
+Deserializes a value into a new cell in the default domain (requires the `serde` feature)
+
+# Example
+```
+# use hzrd::HzrdCell;
+let cell: HzrdCell<String> = serde_json::from_str("\"Hello\"").unwrap();
+assert_eq!(*cell.read(), "Hello");
+```
+*/
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + 'static> serde::Deserialize<'de> for HzrdCell<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(HzrdCell::new)
+    }
+}
+
+// ------------------------------
+
+/**
+Read the values of multiple cells, keeping all of them protected at once
+
+Each cell is read with its own hazard pointer, which means this holds `N` hazard pointers for as long as the returned handles are alive. The cells are read one after the other, so the values are not guaranteed to be a consistent snapshot taken at one instant.
+
+# Example
+```
+use hzrd::HzrdCell;
+
+let a = HzrdCell::new(1);
+let b = HzrdCell::new(2);
+let c = HzrdCell::new(3);
+
+let [a, b, c] = hzrd::read_all(&[&a, &b, &c]);
+assert_eq!(*a + *b + *c, 6);
+```
+*/
+pub fn read_all<'a, T: 'static, D: Domain, const N: usize>(
+    cells: &[&'a HzrdCell<T, D>; N],
+) -> [ReadHandle<'a, T>; N] {
+    ::core::array::from_fn(|i| cells[i].read())
+}
+
+// ------------------------------
+
+/// Number of times the value may be replaced during a compare-exchange, before it's considered contended
+const CONTENTION_LIMIT: usize = 1;
+
+/**
+The result of a [`HzrdCell::try_compare_exchange`]
+
+Both failure variants hand back the value that was attempted written, so that nothing is lost.
+*/
+pub enum CasResult<'cell, T> {
+    /// The new value was successfully swapped in
+    Succeeded,
+    /// The cell held a different value, which is held by the returned handle
+    Mismatch(ReadHandle<'cell, T>, T),
+    /// The value of the cell changed too rapidly to read the current value
+    Contended(T),
+}
+
+// ------------------------------
+
+/**
+A reader object for a specific [`HzrdCell`]
+
+The [`HzrdReader`] holds a reference to the value of the [`HzrdCell`], as well as a [`HzrdPtr`] to read from it. When performing many, consecutive reads of a cell this can be much more performant, as you only need to retrieve the [`HzrdPtr`] once.
+
+# Basic usage
+The basics of using a [`HzrdReader`] is to first create a [`HzrdCell`], and then call the [`reader`](`HzrdCell::reader`) method on the cell to retrieve a reader.
+
+```
+# use std::time::Duration;
+#
+# use hzrd::HzrdCell;
+#
+let cell = HzrdCell::new(false);
+
+std::thread::scope(|s| {
+    s.spawn(|| {
+        let mut reader = cell.reader();
+        while !reader.get() {
+            std::hint::spin_loop();
+        }
+        println!("Done!");
+    });
+
+    s.spawn(|| {
+        std::thread::sleep(Duration::from_millis(1));
+        cell.set(true);
+    });
+});
+```
+
+# The elephant in the room
+The keen eye might have observed some of the "funkiness" with [`HzrdReader`] in the previous example: Reading the value requires a mutable/exclusive reference, and thus the reader must be marked as `mut`. Note also that this was not the case for reading, or even writing (!), to the cell itself. Exclusivity is usually associated with mutation, but for the [`HzrdReader`] this is not the case. The reason that reading via a [`HzrdReader`] requires a mutable/exclusive reference is that the reader holds access to only a single hazard pointer. This hazard pointer is used when you read a value, so as long as you hold on the value read that hazard pointer is busy.
+
+```
+# use hzrd::{HzrdCell, HzrdReader};
+#
+let cell = HzrdCell::new([0, 1, 2]);
+
+// NOTE: The reader must be marked as mutable
+let mut reader = cell.reader();
+
+// NOTE: We use associated function syntax here to
+//       emphasize the need for a mutable reference
+let handle = HzrdReader::read(&mut reader);
+assert_eq!(handle[0], 0);
+```
+
+If you need to hold on to multiple values at once, such as an old and a new value for comparison, you can use [`read_shared`](`HzrdReader::read_shared`) instead. This reads the value using a separate set of hazard pointers held by the reader, and so it only requires a shared reference.
+*/
+pub struct HzrdReader<'cell, T, D = GlobalDomain> {
+    value: &'cell AtomicPtr<T>,
+    hzrd_ptr: &'cell HzrdPtr,
+    domain: &'cell D,
+    // Hazard pointers used by `read_shared`, these are reset when not in use and released on drop
+    pool: SharedStack<&'cell HzrdPtr>,
+}
+
+impl<T, D> HzrdReader<'_, T, D> {
+    /**
+    Read the associated value and return a handle holding a reference it
+
+    Note that the reference held by the returned handle is to the value as it was when it was read.
+    If the cell is written to during the lifetime of the handle this will not be reflected in its value.
+
+    The hazard pointer of the reader is reset as soon as the handle is dropped, and so a long-lived reader does not keep the last value it read from being reclaimed.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::new());
+    let mut reader = cell.reader();
+    let string = reader.read();
+    assert!(string.is_empty());
+    ```
+    */
+    pub fn read(&mut self) -> ReadHandle<'_, T> {
+        // SAFETY: The hazard pointer will protect the value
+        unsafe { ReadHandle::read_unchecked(self.value, self.hzrd_ptr, Action::Reset) }
+    }
+
+    /**
+    Read the associated value, skipping the protection if the hazard pointer already protects it
+
+    Unlike [`read`](`HzrdReader::read`) the hazard pointer is not reset when the returned handle is dropped, and so the value is still protected on the next call. If the value is unchanged since then, the hazard pointer is not written to again. This makes repeated reads of a value that rarely changes cheaper.
+
+    Note that the value last read is kept from being reclaimed until the next call to [`read`](`HzrdReader::read`) or [`reset`](`HzrdReader::reset`), or until the reader is dropped.
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(0);
+    let mut reader = cell.reader();
+    assert_eq!(*reader.peek(), 0);
+    assert_eq!(*reader.peek(), 0);
+
+    cell.set(1);
+    assert_eq!(*reader.peek(), 1);
+    ```
+    */
+    pub fn peek(&mut self) -> ReadHandle<'_, T> {
+        // SAFETY: The hazard pointer is only used to protect this value, and will protect the value
+        unsafe { ReadHandle::peek_unchecked(self.value, self.hzrd_ptr) }
+    }
+
+    /**
+    Reset the hazard pointer of the reader, such that the value last read can be reclaimed
+
+    This is only needed after [`peek`](`HzrdReader::peek`), which keeps the value protected after the handle is dropped. A reader which only peeks now and then can call this in between, rather than pinning the value until the next read.
+
+    # Example
+    ```
+    # use hzrd::core::Domain;
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new_in(0, SharedDomain::new());
+    let mut reader = cell.reader();
+    assert_eq!(*reader.peek(), 0);
+
+    cell.set(1);
+    reader.reset();
+    cell.reclaim();
+    assert_eq!(cell.domain().retired_count(), 0);
+    ```
+    */
+    pub fn reset(&mut self) {
+        // SAFETY: The exclusive reference means no handle is using the hazard pointer
+        unsafe { self.hzrd_ptr.reset() };
+    }
+
+    /**
+    Read the associated value and copy it (requires the type to be [`Copy`])
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new('z');
+    let mut reader = cell.reader();
+    assert_eq!(reader.get(), 'z');
+    ```
+    */
+    pub fn get(&mut self) -> T
+    where
+        T: Copy,
+    {
+        *self.read()
+    }
+
+    /**
+    Read the associated value and clone it into a new [`Box`] (requires the type to be [`Clone`])
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    fn takes_box(boxed: Box<String>) -> String {
+        *boxed
+    }
+
+    let cell = HzrdCell::new(String::from("Hello"));
+    let mut reader = cell.reader();
+    assert_eq!(takes_box(reader.read_boxed()), "Hello");
+    ```
+    */
+    pub fn read_boxed(&mut self) -> Box<T>
+    where
+        T: Clone,
+    {
+        Box::new(T::clone(&self.read()))
+    }
+
+    /**
+    Turn the reader into an [`OwnedReader`], which does not borrow the cell
+
+    The given [`Arc`] must hold the cell the reader was constructed from. The hazard pointer of the reader is handed over to the returned reader, which keeps the cell alive through a clone of the [`Arc`]. See [`owned_reader`](HzrdCell::owned_reader) for more details.
+
+    # Panics
+    Panics if the reader was constructed from another cell.
+
+    # Example
+    ```
+    use std::sync::Arc;
+
+    use hzrd::domains::SharedDomain;
+    use hzrd::HzrdCell;
+
+    let cell = Arc::new(HzrdCell::new_in(0, Arc::new(SharedDomain::new())));
+    let mut reader = cell.reader();
+    assert_eq!(reader.get(), 0);
+
+    let mut reader = reader.into_owned(&cell);
+    let thread = std::thread::spawn(move || reader.get());
+    assert_eq!(thread.join().unwrap(), 0);
+    ```
+    */
+    pub fn into_owned(self, cell: &Arc<HzrdCell<T, D>>) -> OwnedReader<T, D> {
+        assert!(
+            ::core::ptr::eq(self.value, &cell.value),
+            "The reader must be turned into an owned reader of the cell it reads"
+        );
+
+        // The hazard pointer is handed over, and must not be released by the reader
+        let reader = ::core::mem::ManuallyDrop::new(self);
+
+        // SAFETY: The reader is never dropped, and so the pool is only dropped here
+        let pool = unsafe { ::core::ptr::read(&reader.pool) };
+        Self::release_pool(pool);
+
+        OwnedReader {
+            cell: Arc::clone(cell),
+            hzrd_ptr: NonNull::from(reader.hzrd_ptr),
+        }
+    }
+
+    /// Release the hazard pointers used by `read_shared`, requires that none of them are in use
+    fn release_pool(pool: SharedStack<&HzrdPtr>) {
+        for hzrd_ptr in pool {
+            // SAFETY: The reader owns the hazard pointers in the pool, and no handles are alive
+            unsafe { hzrd_ptr.release() };
+        }
+    }
+}
+
+impl<T, D: Domain + Sync> HzrdReader<'_, T, D> {
+    /**
+    Read the associated value through a shared reference, and return a handle holding a reference it
+
+    Unlike [`read`](`HzrdReader::read`) this does not use the hazard pointer held by the reader. Instead each handle gets its own hazard pointer from a pool held by the reader, which grows if all the hazard pointers are busy. This allows for holding multiple handles from the same reader at once. The hazard pointers are handed back to the pool when the handles are dropped, and so the pool only grows to the number of handles held at once. They are released back to the domain when the reader is dropped.
+
+    The pool needs to retrieve new hazard pointers from the domain, and so this requires the domain to be [`Sync`].
+
+    # Example
+    ```
+    # use hzrd::HzrdCell;
+    let cell = HzrdCell::new(String::from("Hello"));
+    let reader = cell.reader();
+
+    let old = reader.read_shared();
+    cell.set(String::from("World"));
+    let new = reader.read_shared();
+
+    assert_eq!(*old, "Hello");
+    assert_eq!(*new, "World");
+    ```
+    */
+    pub fn read_shared(&self) -> ReadHandle<'_, T> {
+        // SAFETY: The value is never null
+        let ptr = unsafe { NonNull::new_unchecked(self.value.load(SeqCst)) };
+
+        // A hazard pointer in the pool is claimed by protecting the loaded value, which marks it as busy
+        // SAFETY: The pool is only shared through claiming, and the value is verified below
+        let claimed = self
+            .pool
+            .iter()
+            .find(|hzrd_ptr| unsafe { hzrd_ptr.try_claim(ptr) });
+        let hzrd_ptr = match claimed {
+            Some(hzrd_ptr) => *hzrd_ptr,
+            None => {
+                // The new hazard pointer must be busy before it's shared through the pool
+                let hzrd_ptr = self.domain.hzrd_ptr();
+                // SAFETY: We are the only owner of the new hazard pointer, and the value is verified below
+                unsafe { hzrd_ptr.protect_nonnull(ptr) };
+                *self.pool.push_get(hzrd_ptr)
+            }
+        };
+
+        // SAFETY: The hazard pointer will protect the value, and is handed back to the pool by the handle
+        unsafe { ReadHandle::read_unchecked_in(self.value, hzrd_ptr, Action::Reset, self.domain) }
+    }
+}
+
+impl<T, D> Drop for HzrdReader<'_, T, D> {
+    fn drop(&mut self) {
+        // SAFETY: We are the current owner of the hazard pointer
+        unsafe { self.hzrd_ptr.release() };
+
+        // The handles have been dropped, and so none of the hazard pointers in the pool are in use
+        let pool = ::core::mem::take(&mut self.pool);
+        Self::release_pool(pool);
+    }
+}
+
+// SAFETY: The type held needs to be both `Send` and `Sync`, the domain is only accessed if it's `Sync`
+unsafe impl<T: Send + Sync, D> Send for HzrdReader<'_, T, D> {}
+
+// SAFETY: The type held needs to be both `Send` and `Sync`, the domain is only accessed if it's `Sync`
+unsafe impl<T: Send + Sync, D> Sync for HzrdReader<'_, T, D> {}
+
+// ------------------------------
+
+/**
+A reader object which holds a shared reference to a [`HzrdCell`]
+
+The [`OwnedReader`] works just like the [`HzrdReader`], but instead of borrowing the cell it holds an [`Arc`] to it. It is constructed from an [`Arc`] holding the cell with the [`owned_reader`](`HzrdCell::owned_reader`) method.
+
+```
+use std::sync::Arc;
+use std::time::Duration;
+
+use hzrd::HzrdCell;
+
+let cell = Arc::new(HzrdCell::new(false));
+
+let mut reader = cell.owned_reader();
+let thread = std::thread::spawn(move || {
+    while !reader.get() {
+        std::hint::spin_loop();
+    }
+    println!("Done!");
+});
+
+std::thread::sleep(Duration::from_millis(1));
+cell.set(true);
+thread.join().unwrap();
+```
+*/
+pub struct OwnedReader<T, D = GlobalDomain> {
+    cell: Arc<HzrdCell<T, D>>,
+    // Points into the domain of the cell, which is kept alive by the `Arc`
+    hzrd_ptr: NonNull<HzrdPtr>,
+}
+
+impl<T, D> OwnedReader<T, D> {
+    fn hzrd_ptr(&self) -> &HzrdPtr {
+        // SAFETY: The domain holding the hazard pointer is kept alive by the cell
+        unsafe { self.hzrd_ptr.as_ref() }
+    }
+
+    /**
+    Read the associated value and return a handle holding a reference it
+
+    See [`HzrdReader::read`] for more details.
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::HzrdCell;
+    let cell = Arc::new(HzrdCell::new(String::new()));
+    let mut reader = cell.owned_reader();
+    let string = reader.read();
+    assert!(string.is_empty());
+    ```
+    */
+    pub fn read(&mut self) -> ReadHandle<'_, T> {
+        // SAFETY: The hazard pointer will protect the value
+        unsafe { ReadHandle::read_unchecked(&self.cell.value, self.hzrd_ptr(), Action::Reset) }
+    }
+
+    /**
+    Read the associated value and copy it (requires the type to be [`Copy`])
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::HzrdCell;
+    let cell = Arc::new(HzrdCell::new('z'));
+    let mut reader = cell.owned_reader();
+    assert_eq!(reader.get(), 'z');
+    ```
+    */
+    pub fn get(&mut self) -> T
+    where
+        T: Copy,
+    {
+        *self.read()
+    }
+
+    /**
+    Read the associated value and clone it into a new [`Box`] (requires the type to be [`Clone`])
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::HzrdCell;
+    let cell = Arc::new(HzrdCell::new(vec![1, 2, 3]));
+    let mut reader = cell.owned_reader();
+    assert_eq!(*reader.read_boxed(), [1, 2, 3]);
+    ```
+    */
+    pub fn read_boxed(&mut self) -> Box<T>
+    where
+        T: Clone,
+    {
+        Box::new(T::clone(&self.read()))
+    }
+}
+
+impl<T, D> Drop for OwnedReader<T, D> {
+    fn drop(&mut self) {
+        // SAFETY: We are the current owner of the hazard pointer
+        unsafe { self.hzrd_ptr().release() };
+    }
+}
+
+// SAFETY: The reader holds a shared reference to the cell, so it must be `Sync`
+unsafe impl<T: Send + Sync, D: Send + Sync> Send for OwnedReader<T, D> {}
+
+// SAFETY: The reader holds a shared reference to the cell, so it must be `Sync`
+unsafe impl<T: Send + Sync, D: Send + Sync> Sync for OwnedReader<T, D> {}
+
+// ------------------------------
+
+/**
+A reader which only reads the value of a [`HzrdCell`] if it has changed since it was last observed
+
+The observer is constructed with [`HzrdCell::observe`], and is meant for polling code, such as a UI which only needs to redraw when something changes. Changes are detected by the identity of the values, rather than by comparing them, and so this is cheap even for large values. This also means that writing a value equal to the current one counts as a change.
+
+The last observed value is kept protected by the hazard pointer of the observer, such that its memory can not be reused by a later value, which would hide the change. This means the observer holds on to the last value it observed until it observes a new one, or until it is dropped.
+
+# Example
+```
+use hzrd::HzrdCell;
+
+let cell = HzrdCell::new(vec![0; 1024]);
+let mut observer = cell.observe();
+
+// The first observation always returns the value
+assert!(observer.changed().is_some());
+assert!(observer.changed().is_none());
+
+cell.set(vec![1; 1024]);
+assert_eq!(observer.changed().unwrap()[0], 1);
+assert!(observer.changed().is_none());
+```
+*/
+pub struct Observer<'cell, T> {
+    value: &'cell AtomicPtr<T>,
+    hzrd_ptr: &'cell HzrdPtr,
+    // Address of the last observed value, zero if nothing has been observed
+    last: usize,
+}
+
+impl<T> Observer<'_, T> {
+    /**
+    Read the value if it has changed since it was last observed, otherwise return `None`
+
+    The hazard pointer keeps protecting the value after the handle is dropped, see [`Observer`].
+    */
+    pub fn changed(&mut self) -> Option<ReadHandle<'_, T>> {
+        if self.value.load(SeqCst) as usize == self.last {
+            return None;
+        }
+
+        // SAFETY: The hazard pointer will protect the value, and is only released when the observer is dropped
+        let handle = unsafe { ReadHandle::read_unchecked(self.value, self.hzrd_ptr, Action::Keep) };
+        self.last = &*handle as *const T as usize;
+        Some(handle)
+    }
+}
+
+impl<T> Drop for Observer<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: We are the current owner of the hazard pointer
+        unsafe { self.hzrd_ptr.release() };
+    }
+}
+
+// SAFETY: The type held needs to be both `Send` and `Sync`
+unsafe impl<T: Send + Sync> Send for Observer<'_, T> {}
+
+// SAFETY: The type held needs to be both `Send` and `Sync`
+unsafe impl<T: Send + Sync> Sync for Observer<'_, T> {}
+
+/// Allocate a box for the value, returning the value if the allocation fails
+fn try_box<T>(value: T) -> Result<Box<T>, T> {
+    let layout = alloc::alloc::Layout::new::<T>();
+
+    // Zero-sized types are never allocated
+    if layout.size() == 0 {
+        return Ok(Box::new(value));
+    }
+
+    // SAFETY: The layout has a non-zero size
+    let ptr = unsafe { alloc::alloc::alloc(layout) }.cast::<T>();
+    if ptr.is_null() {
+        return Err(value);
+    }
+
+    // SAFETY: The memory was allocated by the global allocator, with the layout of `T`
+    unsafe {
+        ptr.write(value);
+        Ok(Box::from_raw(ptr))
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::domains::{LocalDomain, SharedDomain};
+    use crate::HzrdCell;
+
+    #[test]
+    fn drop_test() {
+        // Shallow drop
+        let _ = HzrdCell::new(0);
+
+        // Deep drop
+        let _ = HzrdCell::new(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn types() {
+        let _cell_1: HzrdCell<usize> = HzrdCell::new(0);
+        let _cell_2: HzrdCell<_, LocalDomain> = HzrdCell::new_in(0, LocalDomain::new());
+
+        let shared_domain = Arc::new(SharedDomain::new());
+        let _cell_3: HzrdCell<_, Arc<_>> = HzrdCell::new_in(0, Arc::clone(&shared_domain));
+        let _cell_4: HzrdCell<_, &Arc<SharedDomain>> = HzrdCell::new_in(0, &shared_domain);
+
+        let _cell_4: HzrdCell<_, _> = HzrdCell::new_in(0, Box::new(SharedDomain::new()));
+
+        let _cell_5: HzrdCell<usize, _> = HzrdCell::new_in(0, LocalDomain::new());
+        let _cell_6: HzrdCell<usize, LocalDomain> = HzrdCell::new_in(0, LocalDomain::new());
+
+        // Invalid:
+        // let _cell_x: HzrdCell<_> = HzrdCell::new_in(false, Box::new(SharedDomain::new()));
+    }
+
+    #[test]
+    fn from_box() {
+        let boxed = Box::new(String::from("Hello"));
+        let ptr: *const String = &*boxed;
+
+        // The existing allocation should be used as is
+        let cell = HzrdCell::from(boxed);
+        assert!(std::ptr::eq(&*cell.read(), ptr));
+        assert_eq!(*cell.read(), "Hello");
+    }
+
+    #[test]
+    fn single_threaded() {
+        let string = String::new();
+        let cell = HzrdCell::new(string);
+
+        {
+            let handle = cell.read();
+            assert_eq!(handle.len(), 0);
+            assert_eq!(*handle, "");
+        }
+
+        let new_string = String::from("Hello world!");
+        cell.set(new_string);
+
+        {
+            let handle = cell.read();
+            assert_eq!(handle.len(), 12);
+            assert_eq!(*handle, "Hello world!");
+        }
+
+        cell.reclaim();
+    }
+
+    #[test]
+    fn multi_threaded() {
+        let string = String::new();
+        let cell = HzrdCell::new(string);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let handle = cell.read();
+                std::thread::sleep(Duration::from_millis(200));
+                assert_eq!(*handle, "");
+            });
+
+            std::thread::sleep(Duration::from_millis(100));
+
+            s.spawn(|| {
+                let handle = cell.read();
+                assert_eq!(*handle, "");
+                drop(handle);
+
+                let new_string = String::from("Hello world!");
+                cell.set(new_string);
+            });
+        });
+
+        cell.reclaim();
+        assert_eq!(cell.read().clone(), "Hello world!");
+    }
+
+    #[test]
+    fn static_threads() {
+        let cell = Arc::new(HzrdCell::new(Vec::new()));
+
+        let cell_1 = Arc::clone(&cell);
+        let handle_1 = std::thread::spawn(move || {
+            let handle = cell_1.read();
+            std::thread::sleep(Duration::from_millis(200));
+            assert!(handle.is_empty());
+        });
+
+        std::thread::sleep(Duration::from_millis(100));
+
+        let cell_2 = Arc::clone(&cell);
+        let handle_2 = std::thread::spawn(move || {
+            let handle = cell_2.read();
+            assert!(handle.is_empty());
+            drop(handle);
+
+            let new_vec = vec![false, false, true];
+            cell_2.set(new_vec);
+        });
+
+        handle_1.join().unwrap();
+        handle_2.join().unwrap();
+
+        cell.reclaim();
+        assert_eq!(cell.read().as_slice(), [false, false, true]);
+    }
+
+    #[test]
+    fn retirement() {
+        let cell = HzrdCell::new_in(String::new(), SharedDomain::new());
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 0, "{:?}", cell.domain);
+
+        let _handle_1 = cell.read();
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 1, "{:?}", cell.domain);
+
+        cell.set("Hello world".into());
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 1);
+
+        // ------------
+
+        let _handle_2 = cell.read();
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 2, "{:?}", cell.domain);
+
+        cell.set("Pizza world".into());
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 2);
+
+        // ------------
+
+        drop(_handle_2);
+        cell.set("Ramen world".into());
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 1);
+
+        // ------------
+
+        drop(_handle_1);
+        cell.reclaim();
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[test]
+    fn readers() {
+        let cell = HzrdCell::new(vec![0, 1, 2]);
+
+        let readers: Vec<_> = (0..10).map(|_| cell.reader()).collect();
+
+        for mut reader in readers {
+            assert_eq!(reader.read().as_slice(), [0, 1, 2]);
+        }
+    }
+
+    #[test]
+    fn manual_reclaim() {
+        let local_domain = SharedDomain::new();
+        let cell = HzrdCell::new_in([1, 2, 3], local_domain);
+
+        cell.just_set([4, 5, 6]);
+        assert_eq!(
+            cell.domain.number_of_retired_ptrs(),
+            1,
+            "Retired ptrs: {:?}",
+            cell.domain,
+        );
+
+        cell.just_set([7, 8, 9]);
+        assert_eq!(
+            cell.domain.number_of_retired_ptrs(),
+            2,
+            "Retired ptrs: {:?}",
+            cell.domain,
+        );
+
+        cell.reclaim();
+        assert_eq!(
+            cell.domain.number_of_retired_ptrs(),
+            0,
+            "Retired ptrs: {:?}",
+            cell.domain,
+        );
+    }
+
+    #[test]
+    fn domain_strong_count() {
+        let domain = Arc::new(SharedDomain::new());
+        assert_eq!(Arc::strong_count(&domain), 1);
+
+        let cells: Vec<_> = (0..4)
+            .map(|i| HzrdCell::new_in(i, Arc::clone(&domain)))
+            .collect();
+        assert!(cells.iter().all(|cell| cell.domain_strong_count() == 5));
+
+        let mut cells = cells.into_iter();
+        let last = cells.next_back().unwrap();
+        drop(cells);
+        assert_eq!(last.domain_strong_count(), 2);
+
+        drop(domain);
+        assert_eq!(last.domain_strong_count(), 1);
+    }
+
+    #[test]
+    fn read_all() {
+        let domain = SharedDomain::new();
+        let cells = [
+            HzrdCell::new_in(String::from("Hello"), &domain),
+            HzrdCell::new_in(String::from(" "), &domain),
+            HzrdCell::new_in(String::from("world"), &domain),
+        ];
+
+        let [a, b, c] = crate::read_all(&[&cells[0], &cells[1], &cells[2]]);
+        assert_eq!(domain.number_of_hzrd_ptrs(), 3);
+
+        // All values should stay protected while the cells are updated
+        cells.iter().for_each(|cell| cell.set(String::new()));
+        assert_eq!(domain.number_of_retired_ptrs(), 3);
+        assert_eq!(format!("{}{}{}", *a, *b, *c), "Hello world");
+
+        drop((a, b, c));
+        cells[0].reclaim();
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn poll_read() {
+        use std::future::{poll_fn, Future};
+        use std::sync::atomic::{AtomicBool, Ordering::*};
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
+        let done = AtomicBool::new(false);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for i in 1.. {
+                    if done.load(SeqCst) {
+                        break;
+                    }
+                    cell.set(i);
+                }
+            });
+
+            let waker = Waker::from(Arc::new(NoopWaker));
+            let mut cx = Context::from_waker(&waker);
+
+            // Drive a future built on `poll_read` to completion a number of times
+            for _ in 0..100 {
+                let mut future = std::pin::pin!(poll_fn(|cx| cell.poll_read(cx).map(|h| *h)));
+                let value = loop {
+                    match future.as_mut().poll(&mut cx) {
+                        Poll::Ready(value) => break value,
+                        Poll::Pending => std::thread::yield_now(),
+                    }
+                };
+                assert!(value >= 0);
+            }
+
+            done.store(true, SeqCst);
+        });
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn wait_until() {
+        use std::future::Future;
+        use std::task::{Context, Poll, Wake, Waker};
+        use std::thread::Thread;
+
+        struct ThreadWaker(Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for i in 1..=10 {
+                    std::thread::sleep(Duration::from_millis(1));
+                    cell.set(i);
+                }
+            });
+
+            // The waiting thread is parked until it's woken by a write
+            let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+            let mut cx = Context::from_waker(&waker);
+            let mut future = std::pin::pin!(cell.wait_until(|value| *value == 10));
+            let value = loop {
+                match future.as_mut().poll(&mut cx) {
+                    Poll::Ready(handle) => break *handle,
+                    Poll::Pending => std::thread::park(),
+                }
+            };
+            assert_eq!(value, 10);
+        });
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let cell = HzrdCell::new_in(String::from("Hello"), LocalDomain::new());
+
+        let handle = cell.read();
+        assert_eq!(
+            cell.compare_exchange(&handle, String::from("World")),
+            Ok(())
+        );
+        assert_eq!(*handle, "Hello");
+
+        // The handle is now outdated, so the value should be handed back
+        let new = String::from("Hello again");
+        assert_eq!(
+            cell.compare_exchange(&handle, new),
+            Err(String::from("Hello again"))
+        );
+        drop(handle);
+
+        assert_eq!(*cell.read(), "World");
+    }
+
+    #[test]
+    fn replace() {
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
+        let cell = &cell;
+
+        // Every value should be returned by exactly one call to replace
+        let mut replaced: Vec<_> = std::thread::scope(|s| {
+            let handles: Vec<_> = (1..=4)
+                .map(|t| {
+                    s.spawn(move || {
+                        (0..100)
+                            .map(|i| cell.replace(100 * t + i))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect()
+        });
+        replaced.push(cell.get());
+        replaced.sort();
+
+        let mut expected: Vec<_> = (1..=4)
+            .flat_map(|t| (0..100).map(move |i| 100 * t + i))
+            .collect();
+        expected.push(0);
+        expected.sort();
+        assert_eq!(replaced, expected);
+    }
+
+    #[test]
+    fn take() {
+        let cell = HzrdCell::new_in(String::from("Hello"), SharedDomain::new());
+
+        std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4).map(|_| s.spawn(|| cell.take())).collect();
+            let mut taken: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+            taken.sort();
+
+            // Exactly one thread should have gotten the original value
+            assert_eq!(taken, ["", "", "", "Hello"]);
+        });
+
+        assert_eq!(*cell.read(), "");
+        cell.reclaim();
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[test]
+    fn take_if_some() {
+        let cell = HzrdCell::new_in(None, SharedDomain::new());
+        let barrier = std::sync::Barrier::new(4);
+
+        for i in 0..10 {
+            cell.set(Some(i));
+
+            let taken: Vec<_> = std::thread::scope(|s| {
+                let handles: Vec<_> = (0..4)
+                    .map(|_| {
+                        s.spawn(|| {
+                            barrier.wait();
+                            cell.take_if_some()
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            // Exactly one thread should win the race
+            let winners: Vec<_> = taken.into_iter().flatten().collect();
+            assert_eq!(winners, [i]);
+            assert_eq!(*cell.read(), None);
+        }
     }
-}
 
-// SAFETY: The type held needs to be both `Send` and `Sync`
-unsafe impl<T: Send + Sync> Send for HzrdReader<'_, T> {}
+    #[test]
+    fn reader_panic() {
+        let cell = HzrdCell::new_in(String::from("Hello"), SharedDomain::new());
 
-// SAFETY: The type held needs to be both `Send` and `Sync`
-unsafe impl<T: Send + Sync> Sync for HzrdReader<'_, T> {}
+        let result = std::thread::scope(|s| {
+            s.spawn(|| {
+                let handle = cell.read();
+                if handle.len() == 5 {
+                    panic!("Reader panicked while holding a handle");
+                }
+            })
+            .join()
+        });
+        assert!(result.is_err());
 
-// ------------------------------
+        // The hazard pointer should have been released during unwinding
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 1);
+        cell.set(String::from("World"));
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
 
-#[cfg(test)]
-mod tests {
-    use std::sync::Arc;
-    use std::time::Duration;
+        // ...and it should be reused by the next read
+        assert_eq!(*cell.read(), "World");
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 1);
+    }
 
-    use crate::domains::{LocalDomain, SharedDomain};
-    use crate::HzrdCell;
+    #[test]
+    fn set_if_changed() {
+        let cell = HzrdCell::new_in(vec![1, 2, 3], SharedDomain::new());
+
+        for _ in 0..10 {
+            assert!(!cell.set_if_changed(vec![1, 2, 3]));
+        }
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+
+        let handle = cell.read();
+        assert!(cell.set_if_changed(vec![4, 5, 6]));
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 1);
+        assert_eq!(*handle, [1, 2, 3]);
+        assert_eq!(*cell.read(), [4, 5, 6]);
+    }
 
     #[test]
-    fn drop_test() {
-        // Shallow drop
-        let _ = HzrdCell::new(0);
+    fn compare_and_set() {
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
 
-        // Deep drop
-        let _ = HzrdCell::new(vec![1, 2, 3]);
+        // Every increment is only applied if no one else has incremented in the meantime
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..25 {
+                        loop {
+                            let current = cell.get();
+                            if cell.compare_and_set(&current, current + 1) {
+                                break;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        assert_eq!(cell.get(), 100);
     }
 
     #[test]
-    fn types() {
-        let _cell_1: HzrdCell<usize> = HzrdCell::new(0);
-        let _cell_2: HzrdCell<_, LocalDomain> = HzrdCell::new_in(0, LocalDomain::new());
+    fn fetch_update() {
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
 
-        let shared_domain = Arc::new(SharedDomain::new());
-        let _cell_3: HzrdCell<_, Arc<_>> = HzrdCell::new_in(0, Arc::clone(&shared_domain));
-        let _cell_4: HzrdCell<_, &Arc<SharedDomain>> = HzrdCell::new_in(0, &shared_domain);
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..25 {
+                        assert!(cell.fetch_update(|x| Some(x + 1)).is_ok());
+                    }
+                });
+            }
+        });
 
-        let _cell_4: HzrdCell<_, _> = HzrdCell::new_in(0, Box::new(SharedDomain::new()));
+        assert_eq!(cell.get(), 100);
+        assert_eq!(cell.fetch_update(|&x| (x < 100).then_some(0)), Err(100));
+        assert_eq!(cell.get(), 100);
+    }
 
-        let _cell_5: HzrdCell<usize, _> = HzrdCell::new_in(0, LocalDomain::new());
-        let _cell_6: HzrdCell<usize, LocalDomain> = HzrdCell::new_in(0, LocalDomain::new());
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let cells: Vec<HzrdCell<String>> = serde_json::from_str(r#"["Hello", "World"]"#).unwrap();
+        assert_eq!(*cells[0].read(), "Hello");
+        assert_eq!(*cells[1].read(), "World");
+
+        cells[1].set(String::from("hzrd"));
+        let json = serde_json::to_string(&cells).unwrap();
+        assert_eq!(json, r#"["Hello","hzrd"]"#);
+    }
 
-        // Invalid:
-        // let _cell_x: HzrdCell<_> = HzrdCell::new_in(false, Box::new(SharedDomain::new()));
+    #[test]
+    fn swap_cell() {
+        use crate::core::Domain;
+
+        let domain = SharedDomain::new();
+        let front = HzrdCell::new_in(String::from("Hello"), &domain);
+        let back = HzrdCell::new_in(String::new(), &domain);
+
+        let handle = front.read();
+        back.set(String::from("World"));
+        unsafe { back.swap_cell(&front) };
+        assert_eq!(*front.read(), "World");
+        assert_eq!(*back.read(), "Hello");
+
+        // Nothing was retired by the swap
+        assert_eq!(domain.retired_count(), 0);
+
+        // The value moved out of the front is still protected after it's retired
+        back.set(String::new());
+        assert_eq!(domain.retired_count(), 1);
+        assert_eq!(*handle, "Hello");
+
+        drop(handle);
+        domain.reclaim();
+        assert_eq!(domain.retired_count(), 0);
     }
 
     #[test]
-    fn single_threaded() {
-        let string = String::new();
-        let cell = HzrdCell::new(string);
+    fn swap_cell_while_reading() {
+        use std::sync::atomic::{AtomicBool, Ordering::*};
 
-        {
-            let handle = cell.read();
-            assert_eq!(handle.len(), 0);
-            assert_eq!(*handle, "");
-        }
+        let domain = SharedDomain::new();
+        let front = HzrdCell::new_in(String::from("front"), &domain);
+        let back = HzrdCell::new_in(String::from("back"), &domain);
+        let done = AtomicBool::new(false);
 
-        let new_string = String::from("Hello world!");
-        cell.set(new_string);
+        std::thread::scope(|s| {
+            for cell in [&front, &back] {
+                let done = &done;
+                s.spawn(move || {
+                    while !done.load(SeqCst) {
+                        let value = cell.read();
+                        assert!(*value == "front" || *value == "back");
+                    }
+                });
+            }
 
-        {
-            let handle = cell.read();
-            assert_eq!(handle.len(), 12);
-            assert_eq!(*handle, "Hello world!");
-        }
+            for _ in 0..1000 {
+                unsafe { back.swap_cell(&front) };
+            }
+            done.store(true, SeqCst);
+        });
 
-        cell.reclaim();
+        // An even number of swaps puts the values back where they started
+        assert_eq!(*front.read(), "front");
+        assert_eq!(*back.read(), "back");
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
     }
 
     #[test]
-    fn multi_threaded() {
-        let string = String::new();
-        let cell = HzrdCell::new(string);
+    #[should_panic(expected = "Can not swap values between cells in domain")]
+    fn swap_cell_mismatched_domains() {
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
+        let other = HzrdCell::new_in(1, SharedDomain::new());
+        unsafe { other.swap_cell(&cell) };
+    }
 
-        std::thread::scope(|s| {
-            s.spawn(|| {
-                let handle = cell.read();
-                std::thread::sleep(Duration::from_millis(200));
-                assert_eq!(*handle, "");
-            });
+    #[test]
+    fn set_many() {
+        let cell = HzrdCell::new_in(String::from("0"), SharedDomain::new());
+        let handle = cell.read();
 
-            std::thread::sleep(Duration::from_millis(100));
+        cell.set_many((1..=5).map(|i| i.to_string()));
+        assert_eq!(*cell.read(), "5");
 
-            s.spawn(|| {
-                let handle = cell.read();
-                assert_eq!(*handle, "");
-                drop(handle);
+        // Only the value held by the handle is left
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 1);
+        assert_eq!(*handle, "0");
+        drop(handle);
 
-                let new_string = String::from("Hello world!");
-                cell.set(new_string);
-            });
-        });
+        cell.set_many(Vec::new());
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+        assert_eq!(*cell.read(), "5");
+    }
 
+    #[test]
+    fn read_and() {
+        let cell = HzrdCell::new_in(vec![1, 2, 3], SharedDomain::new());
+
+        let sum: i32 = cell.read_and(|vec| vec.iter().sum());
+        assert_eq!(sum, 6);
+
+        // The hazard pointer is released once the closure returns
+        cell.set(Vec::new());
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[test]
+    fn read_shared() {
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
+        let reader = cell.reader();
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 1);
+
+        // Every handle held at once needs its own hazard pointer
+        let handles: Vec<_> = (1..=3)
+            .map(|i| {
+                let handle = reader.read_shared();
+                cell.set(i);
+                handle
+            })
+            .collect();
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 4);
+        assert_eq!(handles.iter().map(|h| **h).collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 3);
+
+        // ...but they are reused once the handles are dropped
+        drop(handles);
         cell.reclaim();
-        assert_eq!(cell.read().clone(), "Hello world!");
+        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+        assert_eq!(*reader.read_shared(), 3);
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 4);
+
+        // The pool only grows to the number of handles held at once
+        for i in 0..100 {
+            let (old, new) = (reader.read_shared(), reader.read_shared());
+            assert_eq!(*old, *new);
+            cell.set(i);
+        }
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 4);
+
+        // The hazard pointers are released back to the domain with the reader
+        drop(reader);
+        let reader = cell.reader();
+        let _handles = [
+            reader.read_shared(),
+            reader.read_shared(),
+            reader.read_shared(),
+        ];
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 4);
     }
 
     #[test]
-    fn static_threads() {
-        let cell = Arc::new(HzrdCell::new(Vec::new()));
+    fn owned_reader() {
+        let cell = Arc::new(HzrdCell::new_in(String::from("Hello"), SharedDomain::new()));
+        let mut reader = cell.owned_reader();
+        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 1);
 
-        let cell_1 = Arc::clone(&cell);
-        let handle_1 = std::thread::spawn(move || {
-            let handle = cell_1.read();
-            std::thread::sleep(Duration::from_millis(200));
-            assert!(handle.is_empty());
-        });
+        // The reader keeps the cell alive after the original handle is gone
+        let weak = Arc::downgrade(&cell);
+        cell.set(String::from("World"));
+        drop(cell);
 
-        std::thread::sleep(Duration::from_millis(100));
+        let thread = std::thread::spawn(move || reader.read().clone());
+        assert_eq!(thread.join().unwrap(), "World");
 
-        let cell_2 = Arc::clone(&cell);
-        let handle_2 = std::thread::spawn(move || {
-            let handle = cell_2.read();
-            assert!(handle.is_empty());
-            drop(handle);
+        // The reader was the last reference to the cell
+        assert!(weak.upgrade().is_none());
+    }
 
-            let new_vec = vec![false, false, true];
-            cell_2.set(new_vec);
+    #[test]
+    fn get_or_set_with() {
+        use std::sync::atomic::{AtomicUsize, Ordering::*};
+        use std::sync::Barrier;
+
+        let calls = AtomicUsize::new(0);
+        let barrier = Barrier::new(4);
+        let cell = HzrdCell::new_in(None, SharedDomain::new());
+
+        // All the threads compute a value before any of them can store it, so they all race to initialize
+        let values: Vec<usize> = std::thread::scope(|s| {
+            let threads: Vec<_> = (0..4)
+                .map(|i| {
+                    let (cell, calls, barrier) = (&cell, &calls, &barrier);
+                    s.spawn(move || {
+                        cell.get_or_set_with(|| {
+                            calls.fetch_add(1, SeqCst);
+                            barrier.wait();
+                            i
+                        })
+                    })
+                })
+                .collect();
+            threads.into_iter().map(|t| t.join().unwrap()).collect()
         });
 
-        handle_1.join().unwrap();
-        handle_2.join().unwrap();
+        // Every thread should see the same value, no matter who won
+        assert_eq!(calls.load(SeqCst), 4);
+        assert!(values.iter().all(|&value| value == values[0]));
+        assert_eq!(*cell.read(), Some(values[0]));
 
-        cell.reclaim();
-        assert_eq!(cell.read().as_slice(), [false, false, true]);
+        // Once initialized, the function is not called again
+        assert_eq!(cell.get_or_set_with(|| unreachable!()), values[0]);
+        assert_eq!(calls.load(SeqCst), 4);
     }
 
     #[test]
-    fn retirement() {
-        let cell = HzrdCell::new_in(String::new(), SharedDomain::new());
-        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 0, "{:?}", cell.domain);
-
-        let _handle_1 = cell.read();
-        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 1, "{:?}", cell.domain);
+    fn try_read() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(0, &domain);
+        assert_eq!(cell.try_read().as_deref(), Some(&0));
 
-        cell.set("Hello world".into());
-        assert_eq!(cell.domain.number_of_retired_ptrs(), 1);
+        // Failed reads should not hold on to their hazard pointer
+        std::thread::scope(|s| {
+            s.spawn(|| (1..1000).for_each(|i| cell.set(i)));
+            for _ in 0..1000 {
+                if let Some(handle) = cell.try_read() {
+                    assert!(*handle < 1000);
+                }
+            }
+        });
 
-        // ------------
+        assert_eq!(domain.number_of_hzrd_ptrs(), 1);
+        assert_eq!(*cell.try_read().unwrap(), 999);
+    }
 
-        let _handle_2 = cell.read();
-        assert_eq!(cell.domain.number_of_hzrd_ptrs(), 2, "{:?}", cell.domain);
+    #[test]
+    fn reader_does_not_pin_value() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+        let mut reader = cell.reader();
 
-        cell.set("Pizza world".into());
-        assert_eq!(cell.domain.number_of_retired_ptrs(), 2);
+        let handle = reader.read();
+        cell.set(String::from("World"));
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
 
-        // ------------
+        // Dropping the handle is enough, the reader itself is still alive
+        drop(handle);
+        cell.reclaim();
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+        assert_eq!(*reader.read(), "World");
+    }
 
-        drop(_handle_2);
-        cell.set("Ramen world".into());
-        assert_eq!(cell.domain.number_of_retired_ptrs(), 1);
+    #[test]
+    fn reset_after_peek() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+        let mut reader = cell.reader();
 
-        // ------------
+        // The peeked value stays protected after the handle is dropped
+        drop(reader.peek());
+        cell.set(String::from("World"));
+        cell.reclaim();
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
 
-        drop(_handle_1);
+        reader.reset();
         cell.reclaim();
-        assert_eq!(cell.domain.number_of_retired_ptrs(), 0);
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+        assert_eq!(*reader.peek(), "World");
     }
 
     #[test]
-    fn readers() {
-        let cell = HzrdCell::new(vec![0, 1, 2]);
+    fn reader_peek() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+        let mut reader = cell.reader();
 
-        let readers: Vec<_> = (0..10).map(|_| cell.reader()).collect();
+        // The hazard pointer keeps protecting the value after the handle is dropped
+        assert_eq!(*reader.peek(), "Hello");
+        let hzrd_ptr = reader.hzrd_ptr.get();
+        assert_eq!(*reader.peek(), "Hello");
+        assert_eq!(reader.hzrd_ptr.get(), hzrd_ptr);
 
-        for mut reader in readers {
-            assert_eq!(reader.read().as_slice(), [0, 1, 2]);
-        }
+        cell.set(String::from("World"));
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
+
+        // Peeking at the new value releases the old one
+        assert_eq!(*reader.peek(), "World");
+        cell.reclaim();
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
     }
 
     #[test]
-    fn manual_reclaim() {
-        let local_domain = SharedDomain::new();
-        let cell = HzrdCell::new_in([1, 2, 3], local_domain);
+    fn into_inner() {
+        use crate::core::Domain;
 
-        cell.just_set([4, 5, 6]);
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+
+        let handle = cell.read();
+        cell.set(String::from("World"));
+        drop(handle);
+
+        // The old value is still retired to the domain, only the current value is moved out
+        assert_eq!(cell.into_inner(), "World");
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
+        assert_eq!(domain.reclaim(), 1);
+    }
+
+    #[test]
+    fn observe() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+        let mut observer = cell.observe();
         assert_eq!(
-            cell.domain.number_of_retired_ptrs(),
-            1,
-            "Retired ptrs: {:?}",
-            cell.domain,
+            observer.changed().as_deref().map(String::as_str),
+            Some("Hello")
         );
 
-        cell.just_set([7, 8, 9]);
+        // The last observed value is kept alive, such that its address can not be reused
+        cell.set(String::from("World"));
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
         assert_eq!(
-            cell.domain.number_of_retired_ptrs(),
-            2,
-            "Retired ptrs: {:?}",
-            cell.domain,
+            observer.changed().as_deref().map(String::as_str),
+            Some("World")
         );
+        assert!(observer.changed().is_none());
 
         cell.reclaim();
-        assert_eq!(
-            cell.domain.number_of_retired_ptrs(),
-            0,
-            "Retired ptrs: {:?}",
-            cell.domain,
-        );
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+
+        // Writing an equal value is still a change
+        cell.set(String::from("World"));
+        assert!(observer.changed().is_some());
+
+        drop(observer);
+        cell.reclaim();
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[test]
+    fn partial_eq() {
+        let domain = SharedDomain::new();
+        let a = HzrdCell::new_in(vec![1, 2, 3], &domain);
+        let b = HzrdCell::new_in(vec![1, 2, 3], &domain);
+        assert!(a == b);
+        assert!(a == a);
+
+        b.set(Vec::new());
+        assert!(a != b);
+
+        // Comparing does not keep the values protected
+        a.set(Vec::new());
+        assert!(a == b);
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[test]
+    fn try_clone_handle() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+        let other = HzrdCell::new_in(String::from("Hello"), &domain);
+
+        // The clone keeps protecting the value after the original handle is dropped
+        let handle = cell.read();
+        let clone = cell.try_clone_handle(&handle).unwrap();
+        assert!(other.try_clone_handle(&handle).is_none());
+        drop(handle);
+
+        cell.set(String::from("World"));
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
+        assert!(cell.try_clone_handle(&clone).is_none());
+        assert_eq!(*clone, "Hello");
+
+        drop(clone);
+        cell.reclaim();
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+    }
+
+    #[test]
+    fn set_with() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(vec![0; 16], &domain);
+
+        let handle = cell.read();
+        cell.set_with(|| vec![1; 16]);
+        assert_eq!(*handle, [0; 16]);
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
+        drop(handle);
+
+        cell.set_with(|| vec![2; 16]);
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+        assert_eq!(*cell.read(), [2; 16]);
+    }
+
+    #[test]
+    fn set_boxed() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(0, &domain);
+
+        cell.just_set_boxed(Box::new(1));
+        assert_eq!(domain.number_of_retired_ptrs(), 1);
+
+        // Unlike `just_set_boxed`, this reclaims memory like `set`
+        cell.set_boxed(Box::new(2));
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+        assert_eq!(cell.get(), 2);
+    }
+
+    #[test]
+    fn from_value() {
+        let cell: HzrdCell<String> = String::from("Hello").into();
+        assert_eq!(*cell.read(), "Hello");
+
+        let cell: HzrdCell<i32> = Box::new(5).into();
+        assert_eq!(cell.get(), 5);
+
+        let cells: Vec<HzrdCell<i32>> = (0..4).map(Into::into).collect();
+        assert!(cells
+            .iter()
+            .enumerate()
+            .all(|(i, cell)| cell.get() == i as i32));
+    }
+
+    #[test]
+    fn active_readers() {
+        let domain = LocalDomain::new();
+        let cell = HzrdCell::new_in(0, &domain);
+
+        let mut reader = cell.reader();
+        let handle = cell.read();
+        assert_eq!(cell.active_readers(), 1);
+        let reader_handle = reader.read();
+        assert_eq!(cell.active_readers(), 2);
+        drop(reader_handle);
+        assert_eq!(cell.active_readers(), 1);
+
+        // Readers of the old value are not counted
+        cell.set(1);
+        assert_eq!(cell.active_readers(), 0);
+        assert_eq!(*handle, 0);
+    }
+
+    #[test]
+    fn set_if() {
+        let cell = HzrdCell::new_in(0, SharedDomain::new());
+
+        // The write is abandoned if the cell is written to after the predicate is called
+        let written = cell.set_if(2, |&x| {
+            cell.set(1);
+            x == 0
+        });
+        assert!(!written);
+        assert_eq!(cell.get(), 1);
+
+        assert!(!cell.set_if(2, |&x| x == 0));
+        assert!(cell.set_if(2, |&x| x == 1));
+        assert_eq!(cell.get(), 2);
+    }
+
+    #[test]
+    fn reader_into_owned() {
+        use crate::core::Domain;
+
+        let domain = Arc::new(SharedDomain::new());
+        let cell = Arc::new(HzrdCell::new_in(String::from("Hello"), Arc::clone(&domain)));
+
+        let reader = cell.reader();
+        assert_eq!(*reader.read_shared(), "Hello");
+        assert_eq!(domain.hzrd_ptr_count(), 2);
+
+        // The hazard pointer of the reader is handed over, and no new one is allocated
+        let mut owned = reader.into_owned(&cell);
+        assert_eq!(domain.stats().hzrd_ptrs_active, 1);
+        assert_eq!(*owned.read(), "Hello");
+        assert_eq!(domain.hzrd_ptr_count(), 2);
+
+        drop(owned);
+        assert_eq!(domain.stats().hzrd_ptrs_active, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn reader_into_owned_mismatched_cells() {
+        let cell = Arc::new(HzrdCell::new(0));
+        let other = HzrdCell::new(0);
+        let _owned = other.reader().into_owned(&cell);
+    }
+
+    #[test]
+    fn try_new() {
+        let cell = HzrdCell::try_new_in(String::from("Hello"), SharedDomain::new()).unwrap();
+        assert_eq!(*cell.read(), "Hello");
+
+        // Zero-sized values are never allocated
+        let cell = HzrdCell::try_new(()).unwrap();
+        cell.set(());
+        assert_eq!(*cell.read(), ());
+    }
+
+    #[test]
+    fn read_arc() {
+        let domain = SharedDomain::new();
+        let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+
+        let snapshot = cell.read_arc();
+        cell.set(String::from("World"));
+
+        // The snapshot doesn't keep the old value protected
+        assert_eq!(domain.number_of_retired_ptrs(), 0);
+        assert_eq!(*snapshot, "Hello");
     }
 
     #[test]
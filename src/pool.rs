@@ -0,0 +1,255 @@
+/*!
+Protection of arbitrary atomic pointers, for building custom data structures
+
+The [`HzrdCell`](crate::HzrdCell) handles protection and retirement of its own value, but the same machinery can be used for any [`AtomicPtr`] whose values are retired to a domain. This module provides the [`HazardPool`], which wraps a domain, and the [`Guard`], which protects values read through atomic pointers. All values protected by a guard stay valid until the guard is dropped.
+
+```
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicPtr, Ordering::*};
+
+use hzrd::pool::HazardPool;
+
+let pool = HazardPool::new();
+let value = AtomicPtr::new(Box::into_raw(Box::new(String::from("Hello"))));
+
+let guard = pool.pin();
+let string = unsafe { guard.protect(&value) }.unwrap();
+
+// Swap out the value, and retire the old one
+let old_ptr = value.swap(Box::into_raw(Box::new(String::from("World"))), SeqCst);
+unsafe { pool.retire(NonNull::new(old_ptr).unwrap()) };
+
+// The old value is still protected by the guard
+assert_eq!(string, "Hello");
+drop(guard);
+
+# let _ = unsafe { Box::from_raw(value.load(SeqCst)) };
+```
+*/
+
+use core::ptr::NonNull;
+
+use crate::core::{Domain, HzrdPtr, RetiredPtr};
+use crate::domains::GlobalDomain;
+use crate::stack::SharedStack;
+use crate::sync::AtomicPtr;
+
+/**
+A domain for protecting and retiring values of arbitrary atomic pointers
+
+Values are protected through a [`Guard`], retrieved with [`pin`](HazardPool::pin). See the [module-level documentation](crate::pool) for more details.
+*/
+pub struct HazardPool<D = GlobalDomain> {
+    domain: D,
+}
+
+impl HazardPool {
+    /**
+    Construct a new [`HazardPool`] in the default domain
+
+    # Example
+    ```
+    # use hzrd::pool::HazardPool;
+    let pool = HazardPool::new();
+    ```
+    */
+    pub const fn new() -> Self {
+        Self::new_in(GlobalDomain)
+    }
+}
+
+impl<D> HazardPool<D> {
+    /**
+    Construct a new [`HazardPool`] in the given domain
+
+    # Example
+    ```
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::pool::HazardPool;
+    let pool = HazardPool::new_in(SharedDomain::new());
+    ```
+    */
+    pub const fn new_in(domain: D) -> Self {
+        Self { domain }
+    }
+
+    /// Get a reference to the domain of the pool
+    pub fn domain(&self) -> &D {
+        &self.domain
+    }
+}
+
+impl<D: Domain> HazardPool<D> {
+    /**
+    Get a new guard, which can protect any number of values
+
+    # Example
+    ```
+    # use hzrd::pool::HazardPool;
+    let pool = HazardPool::new();
+    let guard = pool.pin();
+    ```
+    */
+    pub fn pin(&self) -> Guard<'_, D> {
+        Guard {
+            domain: &self.domain,
+            hzrd_ptrs: SharedStack::new(),
+        }
+    }
+
+    /**
+    Retire a value which has been removed from its atomic pointer, and reclaim memory if possible
+
+    The value is freed once it's no longer protected by any guard.
+
+    # Safety
+    - The pointer must have been allocated by [`Box`], and must not be retired more than once
+    - The pointer must no longer be reachable through any atomic pointer, such that it can not be protected again
+    */
+    pub unsafe fn retire<T: 'static>(&self, ptr: NonNull<T>) {
+        // SAFETY: The pointer is heap-allocated, and is no longer reachable
+        self.domain.retire(unsafe { RetiredPtr::new(ptr) });
+    }
+
+    /**
+    Reclaim available memory, if possible
+
+    See [`Domain::reclaim`] for more details.
+    */
+    pub fn reclaim(&self) -> usize {
+        self.domain.reclaim()
+    }
+}
+
+impl Default for HazardPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> core::fmt::Debug for HazardPool<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HazardPool").finish_non_exhaustive()
+    }
+}
+
+/**
+Protects values read from atomic pointers, until the guard is dropped
+
+Each value protected uses a separate hazard pointer from the domain, and all of them are released when the guard is dropped. A guard should therefore be short-lived, as it prevents the values it protects from being reclaimed.
+*/
+pub struct Guard<'pool, D> {
+    domain: &'pool D,
+    hzrd_ptrs: SharedStack<&'pool HzrdPtr>,
+}
+
+impl<D: Domain> Guard<'_, D> {
+    /**
+    Protect the current value of the atomic pointer, and return a reference to it
+
+    Returns `None` if the atomic pointer is null.
+
+    # Safety
+    - All values of the atomic pointer must have been allocated by [`Box`]
+    - Values swapped out of the atomic pointer must be retired to the domain of the pool, see [`HazardPool::retire`]
+
+    # Example
+    ```
+    # use std::sync::atomic::AtomicPtr;
+    # use hzrd::pool::HazardPool;
+    let pool = HazardPool::new();
+    let empty = AtomicPtr::<i32>::new(std::ptr::null_mut());
+
+    let guard = pool.pin();
+    assert!(unsafe { guard.protect(&empty) }.is_none());
+    ```
+    */
+    pub unsafe fn protect<T>(&self, value: &AtomicPtr<T>) -> Option<&T> {
+        let hzrd_ptr = self.domain.hzrd_ptr();
+
+        // SAFETY: We are the owner of the hazard pointer
+        let Some(loaded) = (unsafe { hzrd_ptr.try_load_protected(value) }) else {
+            // Nothing is protected, and so the hazard pointer is not held on to by the guard
+            // SAFETY: We are still the owner of the hazard pointer
+            unsafe { hzrd_ptr.release() };
+            return None;
+        };
+        let ptr = NonNull::from(loaded);
+        self.hzrd_ptrs.push(hzrd_ptr);
+
+        // SAFETY: The value is protected until the guard is dropped
+        Some(unsafe { ptr.as_ref() })
+    }
+}
+
+impl<D> Drop for Guard<'_, D> {
+    fn drop(&mut self) {
+        for hzrd_ptr in &self.hzrd_ptrs {
+            // SAFETY: We are the current owner of the hazard pointer
+            unsafe { hzrd_ptr.release() };
+        }
+    }
+}
+
+impl<D> core::fmt::Debug for Guard<'_, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Guard").finish_non_exhaustive()
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::ptr::NonNull;
+    use std::sync::atomic::{AtomicPtr, Ordering::*};
+
+    use super::HazardPool;
+    use crate::core::Domain;
+    use crate::domains::SharedDomain;
+
+    #[test]
+    fn protect_until_dropped() {
+        let pool = HazardPool::new_in(SharedDomain::new());
+        let boxed = |i: i32| Box::into_raw(Box::new(i));
+        let value = AtomicPtr::new(boxed(0));
+
+        let guard = pool.pin();
+        let values: Vec<i32> = (1..=3)
+            .map(|i| {
+                let current = unsafe { guard.protect(&value) }.unwrap();
+                let old_ptr = value.swap(boxed(i), SeqCst);
+                unsafe { pool.retire(NonNull::new(old_ptr).unwrap()) };
+                *current
+            })
+            .collect();
+
+        // All the values protected by the guard are kept alive
+        assert_eq!(values, [0, 1, 2]);
+        assert_eq!(pool.domain().retired_count(), 3);
+        assert_eq!(pool.domain().hzrd_ptr_count(), 3);
+
+        drop(guard);
+        assert_eq!(pool.reclaim(), 3);
+
+        // The hazard pointers are reused by the next guard
+        let guard = pool.pin();
+        assert_eq!(unsafe { guard.protect(&value) }, Some(&3));
+        assert_eq!(pool.domain().hzrd_ptr_count(), 3);
+
+        let _ = unsafe { Box::from_raw(value.load(SeqCst)) };
+    }
+
+    #[test]
+    fn protect_null() {
+        let pool = HazardPool::new_in(SharedDomain::new());
+        let empty = AtomicPtr::<i32>::new(std::ptr::null_mut());
+
+        // A null pointer does not hold on to a hazard pointer, and so the same one is reused
+        let guard = pool.pin();
+        for _ in 0..10 {
+            assert!(unsafe { guard.protect(&empty) }.is_none());
+        }
+        assert_eq!(pool.domain().hzrd_ptr_count(), 1);
+    }
+}
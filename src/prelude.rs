@@ -0,0 +1,16 @@
+/*!
+Re-exports of the most commonly used types of this crate
+
+```
+use hzrd::prelude::*;
+
+let cell = HzrdCell::new_in(0, SharedDomain::new());
+let mut reader: HzrdReader<_, _> = cell.reader();
+let handle: ReadHandle<_> = reader.read();
+assert_eq!(*handle, 0);
+```
+*/
+
+pub use crate::core::{Domain, ReadHandle};
+pub use crate::domains::{GlobalDomain, LocalDomain, SharedDomain};
+pub use crate::{HzrdCell, HzrdReader, OwnedReader};
@@ -0,0 +1,60 @@
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::core::Domain;
+use crate::domains::GlobalDomain;
+
+/**
+Spawn a background thread which periodically reclaims memory in the [`GlobalDomain`]
+
+Memory is usually only reclaimed when a cell is written to, which means garbage left behind by cells which are no longer written to is never freed. The background thread calls [`reclaim`](`Domain::reclaim`) on the global domain once every `interval`, until it's stopped through the returned [`ReclaimerHandle`].
+
+# Example
+```
+use std::time::Duration;
+
+let reclaimer = hzrd::spawn_global_reclaimer(Duration::from_millis(100));
+
+// ... use cells in the global domain ...
+
+reclaimer.stop();
+```
+*/
+pub fn spawn_global_reclaimer(interval: Duration) -> ReclaimerHandle {
+    let (sender, receiver) = mpsc::channel::<()>();
+
+    // The thread stops once it receives a message, or the handle is dropped
+    let thread = std::thread::spawn(move || {
+        while let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+            GlobalDomain.reclaim();
+        }
+    });
+
+    ReclaimerHandle { sender, thread }
+}
+
+/**
+Handle to a background thread spawned by [`spawn_global_reclaimer`]
+
+Dropping the handle also stops the background thread, but without waiting for it to finish.
+*/
+pub struct ReclaimerHandle {
+    sender: Sender<()>,
+    thread: JoinHandle<()>,
+}
+
+impl ReclaimerHandle {
+    /// Stop the background thread, and wait for it to finish
+    pub fn stop(self) {
+        // The thread may already have stopped, in which case there is nothing to do
+        let _ = self.sender.send(());
+        let _ = self.thread.join();
+    }
+}
+
+impl std::fmt::Debug for ReclaimerHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReclaimerHandle").finish_non_exhaustive()
+    }
+}
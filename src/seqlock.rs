@@ -0,0 +1,215 @@
+/*!
+A reclamation-free alternative to [`HzrdCell`](crate::HzrdCell) for small [`Copy`] types
+
+This module provides the [`SeqCell`], which is based on a sequence lock (seqlock) rather than hazard pointers. The value is stored inline, next to a version counter. Writers bump the version before and after writing the value, and readers retry if the version changed while they were reading. No allocation, hazard pointer, or reclamation is involved.
+
+# When to prefer [`SeqCell`] over [`HzrdCell`](crate::HzrdCell)
+- The value is small and [`Copy`], such as an integer, a timestamp or a small struct of these
+- Reads vastly outnumber writes, and read latency matters
+
+For anything else [`HzrdCell`](crate::HzrdCell) is the better choice. Readers of a [`SeqCell`] copy out the whole value on each attempt, and have to retry if a write happens at the same time. Large values, or values which are written often, therefore make reads slow. A [`SeqCell`] can also not hand out references to its value, as the value may be overwritten at any time.
+
+```
+use hzrd::seqlock::SeqCell;
+
+let timestamp = SeqCell::new((0_u64, 0_u32));
+timestamp.set((1, 500));
+assert_eq!(timestamp.get(), (1, 500));
+```
+*/
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{fence, AtomicUsize, Ordering::*};
+
+/**
+Holds a small [`Copy`] value, protected by a sequence lock
+
+See the [module-level documentation](crate::seqlock) for more details.
+
+# Example
+```
+use hzrd::seqlock::SeqCell;
+
+let cell = SeqCell::new((1, 2));
+cell.set((3, 4));
+assert_eq!(cell.get(), (3, 4));
+```
+*/
+pub struct SeqCell<T> {
+    /// Odd while a write is in progress
+    seq: AtomicUsize,
+    value: UnsafeCell<T>,
+}
+
+impl<T: Copy> SeqCell<T> {
+    /**
+    Construct a new [`SeqCell`] with the given value
+
+    # Example
+    ```
+    # use hzrd::seqlock::SeqCell;
+    let cell = SeqCell::new(0);
+    # assert_eq!(cell.get(), 0);
+    ```
+    */
+    pub const fn new(value: T) -> Self {
+        Self {
+            seq: AtomicUsize::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /**
+    Get the value of the cell
+
+    This never blocks on a lock, but will retry if the read overlaps with a write.
+
+    # Example
+    ```
+    # use hzrd::seqlock::SeqCell;
+    let cell = SeqCell::new(['a', 'b']);
+    assert_eq!(cell.get(), ['a', 'b']);
+    ```
+    */
+    pub fn get(&self) -> T {
+        loop {
+            let seq = self.seq.load(Acquire);
+            if seq % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            // SAFETY: The pointer is valid, and the value is discarded if the read was torn
+            let value = unsafe { core::ptr::read_volatile(self.value.get()) };
+
+            fence(Acquire);
+            if self.seq.load(Relaxed) == seq {
+                return value;
+            }
+        }
+    }
+
+    /**
+    Set the value of the cell
+
+    Concurrent writers are serialized, as the version counter also acts as a lock between writers.
+
+    # Example
+    ```
+    # use hzrd::seqlock::SeqCell;
+    let cell = SeqCell::new(0);
+    cell.set(1);
+    # assert_eq!(cell.get(), 1);
+    ```
+    */
+    pub fn set(&self, value: T) {
+        // Mark the write as started, by making the version odd
+        let mut seq = self.seq.load(Relaxed);
+        loop {
+            if seq % 2 == 1 {
+                core::hint::spin_loop();
+                seq = self.seq.load(Relaxed);
+                continue;
+            }
+
+            match self
+                .seq
+                .compare_exchange_weak(seq, seq + 1, Acquire, Relaxed)
+            {
+                Ok(_) => break,
+                Err(current) => seq = current,
+            }
+        }
+        fence(Release);
+
+        // SAFETY: We hold the write "lock", and readers discard anything read during the write
+        unsafe { core::ptr::write_volatile(self.value.get(), value) };
+
+        // Mark the write as finished
+        self.seq.store(seq.wrapping_add(2), Release);
+    }
+
+    /**
+    Get a mutable reference to the value of the cell
+
+    This is safe as the mutable reference guarantees that no other threads are accessing the cell.
+    */
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Consume the cell, returning the value held
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: Copy + Default> Default for SeqCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Copy + core::fmt::Debug> core::fmt::Debug for SeqCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SeqCell").field(&self.get()).finish()
+    }
+}
+
+// SAFETY: The value is only ever copied in and out of the cell
+unsafe impl<T: Copy + Send> Sync for SeqCell<T> {}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering::*};
+
+    use super::SeqCell;
+
+    #[test]
+    fn single_threaded() {
+        let mut cell = SeqCell::new(0_u64);
+        assert_eq!(cell.get(), 0);
+
+        cell.set(1);
+        assert_eq!(cell.get(), 1);
+
+        *cell.get_mut() += 1;
+        assert_eq!(cell.into_inner(), 2);
+    }
+
+    // The reads racing with writes are data races in the eyes of miri,
+    // even though the torn values are always discarded
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn no_torn_reads() {
+        let cell = SeqCell::new([0_u64; 8]);
+        let done = AtomicBool::new(false);
+
+        std::thread::scope(|s| {
+            for _ in 0..2 {
+                s.spawn(|| {
+                    for i in 1..=1000 {
+                        cell.set([i; 8]);
+                    }
+                });
+            }
+
+            s.spawn(|| {
+                while !done.load(SeqCst) {
+                    let value = cell.get();
+                    assert!(value.iter().all(|&v| v == value[0]), "{value:?}");
+                }
+            });
+
+            s.spawn(|| {
+                // Stop reading once the last value has been written
+                while cell.get()[0] != 1000 {
+                    std::thread::yield_now();
+                }
+                done.store(true, SeqCst);
+            });
+        });
+    }
+}
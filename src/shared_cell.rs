@@ -0,0 +1,313 @@
+/*!
+A cell holding reference-counted values, with cheaply cloneable guards
+
+This module provides the [`SharedHzrdCell`], which holds its value in an [`Arc`]. Reading the value with [`load`](SharedHzrdCell::load) returns a [`Guard`], which protects the value with a hazard pointer, just like a [`ReadHandle`]. The guard can however be cloned, in which case the clone holds a new reference to the [`Arc`] instead of a hazard pointer. Cloning a guard therefore never touches the domain, and the clones can be kept around for as long as needed.
+
+```
+use hzrd::shared_cell::SharedHzrdCell;
+
+let cell = SharedHzrdCell::new(String::from("Hello"));
+
+let guard = cell.load();
+let clone = guard.clone();
+drop(guard);
+
+cell.store(String::from("World").into());
+assert_eq!(*clone, "Hello");
+assert_eq!(*cell.load(), "World");
+```
+
+# Migrating from `arc-swap`
+The API is modelled after `ArcSwap` from the [`arc-swap`](https://docs.rs/arc-swap) crate, and most methods map directly:
+
+| `arc_swap::ArcSwap` | [`SharedHzrdCell`]                          |
+|---------------------|---------------------------------------------|
+| `ArcSwap::new`      | [`SharedHzrdCell::from_arc`]                |
+| `ArcSwap::from_pointee` | [`SharedHzrdCell::new`]                 |
+| `ArcSwap::load`     | [`SharedHzrdCell::load`]                    |
+| `ArcSwap::load_full`| [`SharedHzrdCell::load_full`]               |
+| `ArcSwap::store`    | [`SharedHzrdCell::store`]                   |
+| `ArcSwap::swap`     | [`SharedHzrdCell::swap`]                    |
+| `Guard::into_inner` | [`Guard::into_arc`]                         |
+
+Unlike `arc-swap`, the guards borrow the cell, and the values are reclaimed through the domain of the cell.
+*/
+
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use crate::core::{Domain, ReadHandle};
+use crate::domains::GlobalDomain;
+use crate::HzrdCell;
+
+/**
+Holds a value in an [`Arc`], which can be read through cheaply cloneable guards
+
+See the [module-level documentation](crate::shared_cell) for more details.
+
+# Example
+```
+use hzrd::shared_cell::SharedHzrdCell;
+
+let cell = SharedHzrdCell::new(vec![1, 2, 3]);
+
+std::thread::scope(|s| {
+    s.spawn(|| cell.store(vec![4, 5, 6].into()));
+
+    let guard = cell.load();
+    assert!(*guard == [1, 2, 3] || *guard == [4, 5, 6]);
+});
+```
+*/
+pub struct SharedHzrdCell<T, D = GlobalDomain> {
+    cell: HzrdCell<Arc<T>, D>,
+}
+
+impl<T: 'static> SharedHzrdCell<T> {
+    /**
+    Construct a new [`SharedHzrdCell`] with the given value in the default domain
+
+    # Example
+    ```
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new(0);
+    # assert_eq!(*cell.load(), 0);
+    ```
+    */
+    pub fn new(value: T) -> Self {
+        Self::from_arc(Arc::new(value))
+    }
+
+    /**
+    Construct a new [`SharedHzrdCell`] holding the given [`Arc`] in the default domain
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let value = Arc::new(0);
+    let cell = SharedHzrdCell::from_arc(Arc::clone(&value));
+    assert!(Arc::ptr_eq(&cell.load_full(), &value));
+    ```
+    */
+    pub fn from_arc(value: Arc<T>) -> Self {
+        Self::new_in(value, GlobalDomain)
+    }
+}
+
+impl<T: 'static, D> SharedHzrdCell<T, D> {
+    /**
+    Construct a new [`SharedHzrdCell`] holding the given [`Arc`] in the given domain
+
+    See [`HzrdCell::new_in`] for more details.
+
+    # Example
+    ```
+    # use std::sync::Arc;
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new_in(Arc::new(0), SharedDomain::new());
+    # assert_eq!(*cell.load(), 0);
+    ```
+    */
+    pub fn new_in(value: Arc<T>, domain: D) -> Self {
+        Self {
+            cell: HzrdCell::new_in(value, domain),
+        }
+    }
+}
+
+impl<T: 'static, D: Domain> SharedHzrdCell<T, D> {
+    /**
+    Get a guard holding the current value
+
+    The guard protects the value with a hazard pointer until it's dropped. See [`Guard`] for more details.
+
+    # Example
+    ```
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new(String::from("Hello"));
+    assert_eq!(cell.load().len(), 5);
+    ```
+    */
+    pub fn load(&self) -> Guard<'_, T> {
+        Guard {
+            inner: Inner::Protected(self.cell.read()),
+        }
+    }
+
+    /**
+    Get a new reference to the current value
+
+    The value is protected only while the reference count is incremented, and so this holds no hazard pointer afterwards.
+
+    # Example
+    ```
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new(0);
+    let value = cell.load_full();
+    cell.store(1.into());
+    assert_eq!(*value, 0);
+    ```
+    */
+    pub fn load_full(&self) -> Arc<T> {
+        Arc::clone(&self.cell.read())
+    }
+
+    /**
+    Store a new value in the cell
+
+    The old value is retired, and is released once no hazard pointer protects it. See [`HzrdCell::set`] for more details.
+
+    # Example
+    ```
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new(0);
+    cell.store(1.into());
+    # assert_eq!(*cell.load(), 1);
+    ```
+    */
+    pub fn store(&self, value: Arc<T>) {
+        self.cell.set(value);
+    }
+
+    /**
+    Store a new value in the cell, and return the old one
+
+    # Example
+    ```
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new(0);
+    assert_eq!(*cell.swap(1.into()), 0);
+    # assert_eq!(*cell.load(), 1);
+    ```
+    */
+    pub fn swap(&self, value: Arc<T>) -> Arc<T> {
+        self.cell.replace(value)
+    }
+
+    /**
+    Reclaim available memory, if possible
+
+    See [`HzrdCell::reclaim`] for more details.
+    */
+    pub fn reclaim(&self) {
+        self.cell.reclaim();
+    }
+}
+
+impl<T: 'static> From<Arc<T>> for SharedHzrdCell<T> {
+    fn from(value: Arc<T>) -> Self {
+        Self::from_arc(value)
+    }
+}
+
+impl<T, D> core::fmt::Debug for SharedHzrdCell<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedHzrdCell").finish_non_exhaustive()
+    }
+}
+
+/**
+Holds a value read from a [`SharedHzrdCell`], see [`SharedHzrdCell::load`]
+
+A guard returned by [`load`](SharedHzrdCell::load) protects the value with a hazard pointer. Cloning the guard instead increments the reference count of the value, and the clone holds no hazard pointer. Use [`into_arc`](Guard::into_arc) to turn a guard into a plain [`Arc`].
+
+# Example
+```
+# use hzrd::shared_cell::SharedHzrdCell;
+let cell = SharedHzrdCell::new(0);
+let guards = vec![cell.load(); 8];
+assert!(guards.iter().all(|guard| **guard == 0));
+```
+*/
+pub struct Guard<'cell, T> {
+    inner: Inner<'cell, T>,
+}
+
+enum Inner<'cell, T> {
+    Protected(ReadHandle<'cell, Arc<T>>),
+    Owned(Arc<T>),
+}
+
+impl<T> Guard<'_, T> {
+    /**
+    Turn the guard into an [`Arc`], releasing the hazard pointer if the guard holds one
+
+    # Example
+    ```
+    # use hzrd::shared_cell::SharedHzrdCell;
+    let cell = SharedHzrdCell::new(String::from("Hello"));
+    let value = cell.load().into_arc();
+    assert_eq!(*value, "Hello");
+    ```
+    */
+    pub fn into_arc(self) -> Arc<T> {
+        match self.inner {
+            Inner::Protected(handle) => Arc::clone(&handle),
+            Inner::Owned(arc) => arc,
+        }
+    }
+
+    fn as_arc(&self) -> &Arc<T> {
+        match &self.inner {
+            Inner::Protected(handle) => handle,
+            Inner::Owned(arc) => arc,
+        }
+    }
+}
+
+impl<T> Clone for Guard<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Inner::Owned(Arc::clone(self.as_arc())),
+        }
+    }
+}
+
+impl<T> Deref for Guard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_arc()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Guard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::SharedHzrdCell;
+    use crate::core::Domain;
+    use crate::domains::SharedDomain;
+
+    #[test]
+    fn clone_guard() {
+        let domain = SharedDomain::new();
+        let cell = SharedHzrdCell::new_in(Arc::new(String::from("Hello")), &domain);
+
+        let guard = cell.load();
+        let clones = vec![guard.clone(); 4];
+
+        // Only the original guard holds a hazard pointer
+        cell.store(Arc::new(String::from("World")));
+        assert_eq!(domain.retired_count(), 1);
+        drop(guard);
+        cell.reclaim();
+        assert_eq!(domain.retired_count(), 0);
+
+        // The clones keep the old value alive
+        assert!(clones.iter().all(|clone| **clone == "Hello"));
+        let value = clones[0].clone().into_arc();
+        drop(clones);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
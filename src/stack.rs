@@ -1,6 +1,25 @@
-use std::fmt::Debug;
-use std::marker::PhantomData;
-use std::sync::atomic::{AtomicPtr, Ordering::*};
+/*!
+A lock-free stack, where values are pushed and popped one at a time, or removed by taking the whole chain
+
+# Memory model
+Nodes are only ever written to before they are published, and a node is published by the `Release` exchange of the top of a stack. Anyone who loads the top with `Acquire` therefore sees the full chain below it, including the `next` pointers of every node, and so all loads of `next` pointers can be `Relaxed`. This also holds transitively for chains which are taken from one stack and pushed onto another.
+
+# Popping
+Any number of threads may pop concurrently with each other and with pushes. The top node is protected by a hazard pointer from the [`GlobalDomain`] while it's being unlinked, and the popped node is retired to the domain instead of being freed. A node can therefore not be freed and reused while another thread still expects it at the top, which rules out the ABA problem. The domains are themselves built on this stack, but never pop from it, and so there is no recursion.
+
+The stack gives no ordering guarantees beyond this. In particular, the hazard pointer protocol requires that protecting a value and scanning the hazard pointers are ordered by `SeqCst` fences, and these fences are issued by the readers and the domains, not the stack.
+*/
+
+use alloc::alloc::{dealloc, Layout};
+use alloc::boxed::Box;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::sync::atomic::Ordering::*;
+
+use crate::core::{Domain, RetiredPtr};
+use crate::domains::GlobalDomain;
+use crate::sync::{fence, AtomicPtr};
 
 #[derive(Debug)]
 pub struct Node<T> {
@@ -9,9 +28,11 @@ pub struct Node<T> {
 }
 
 impl<T> Node<T> {
-    pub const fn new(val: T) -> Self {
-        let null = AtomicPtr::new(std::ptr::null_mut());
-        Self { val, next: null }
+    const_fn! {
+        pub fn new(val: T) -> Self {
+            let null = AtomicPtr::new(core::ptr::null_mut());
+            Self { val, next: null }
+        }
     }
 }
 
@@ -20,23 +41,31 @@ pub struct SharedStack<T> {
 }
 
 impl<T> SharedStack<T> {
-    /// Create a new, empty stack
-    pub const fn new() -> Self {
-        Self {
-            top: AtomicPtr::new(std::ptr::null_mut()),
+    const_fn! {
+        /// Create a new, empty stack
+        pub fn new() -> Self {
+            Self {
+                top: AtomicPtr::new(core::ptr::null_mut()),
+            }
         }
     }
 
     fn __push(&self, node: *mut Node<T>) {
-        std::sync::atomic::fence(SeqCst);
+        self.__push_chain(node, node);
+    }
 
-        let mut old_top = self.top.load(Acquire);
+    /// Push a chain of linked nodes onto the stack, where `head` is the top of the chain
+    fn __push_chain(&self, head: *mut Node<T>, tail: *mut Node<T>) {
+        // The old top is never dereferenced, only linked to
+        let mut old_top = self.top.load(Relaxed);
         loop {
-            // SAFETY: We know that this pointer is valid, we just made it
-            unsafe { &*node }.next.store(old_top, Release);
+            // SAFETY: We know that this pointer is valid, we own the chain
+            // The chain is not yet published, and so this is published by the exchange below
+            unsafe { &*tail }.next.store(old_top, Relaxed);
 
-            // We want to exchange the top with our new node, but only if the top is unchanged
-            match self.top.compare_exchange(old_top, node, AcqRel, Acquire) {
+            // We want to exchange the top with our new chain, but only if the top is unchanged
+            // Release: Publishes the chain to anyone who loads the top with `Acquire`
+            match self.top.compare_exchange(old_top, head, Release, Relaxed) {
                 // The exchange was successful, the node has been pushed!
                 // We can now update the count of the list and exit the loop
                 Ok(_) => break,
@@ -49,7 +78,7 @@ impl<T> SharedStack<T> {
     /// Push a new value onto the stack
     pub fn push(&self, val: T) {
         let node = Box::into_raw(Box::new(Node::new(val)));
-        let _ = self.__push(node);
+        self.__push(node);
     }
 
     /// Push a new value onto the stack and return a reference to the value
@@ -63,35 +92,178 @@ impl<T> SharedStack<T> {
     pub fn push_mut(&mut self, val: T) {
         let node = Box::into_raw(Box::new(Node::new(val)));
 
-        let old_top = self.top.load(Acquire);
-        unsafe { &*node }.next.store(old_top, Release);
-
-        // This should always succeed
-        let _exchange_result = self.top.compare_exchange(old_top, node, SeqCst, Relaxed);
-        debug_assert!(_exchange_result.is_ok());
+        // We have exclusive access, the stack is published by whoever later shares it
+        let old_top = self.top.load(Relaxed);
+        unsafe { &*node }.next.store(old_top, Relaxed);
+        self.top.store(node, Relaxed);
     }
 
+    /// Push all the values of another stack onto this stack, keeping their order
     pub fn push_stack(&self, stack: Self) {
-        // TODO: This can be done much more efficiently
-        for val in stack {
-            let node = Box::into_raw(Box::new(Node::new(val)));
-            let _ = self.__push(node);
+        // We own the stack, and so all of its nodes are already visible to us
+        let head = stack.top.load(Relaxed);
+        core::mem::forget(stack);
+        if head.is_null() {
+            return;
+        }
+
+        // The nodes are reused as they are, we only need to find the end of the chain
+        let mut tail = head;
+        loop {
+            // SAFETY: We own all the nodes of the chain
+            let next = unsafe { &*tail }.next.load(Relaxed);
+            if next.is_null() {
+                break;
+            }
+            tail = next;
         }
+
+        self.__push_chain(head, tail);
     }
 
+    /// Pop the top value off the stack, if there is one
+    ///
+    /// This is lock-free, and may run concurrently with any number of pushes and pops. The node
+    /// itself is retired to the [`GlobalDomain`], as other threads may still be reading it.
+    ///
+    /// # Safety
+    /// - The stack may not be taken from, filtered or iterated over at the same time
+    /// - No references to values in the stack may be held, e.g. from [`push_get`](SharedStack::push_get)
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub unsafe fn pop(&self) -> Option<T> {
+        let hzrd_ptr = GlobalDomain.hzrd_ptr();
+
+        let top = loop {
+            // Acquire: Synchronizes with the push of the chain, making all the nodes visible to us
+            let top = self.top.load(Acquire);
+            if top.is_null() {
+                // SAFETY: We are the owner of the hazard pointer, and nothing is protected by it
+                unsafe { hzrd_ptr.release() };
+                return None;
+            }
+
+            // SAFETY: We are the owner of the hazard pointer, and the top is verified below
+            unsafe { hzrd_ptr.protect(top) };
+
+            // The protection must be visible to poppers before the top is verified
+            fence(SeqCst);
+            if self.top.load(Acquire) != top {
+                continue;
+            }
+
+            // SAFETY: The node is protected, and so it can not be freed while we read it
+            let next = unsafe { &*top }.next.load(Relaxed);
+
+            // A protected node is never reused, so if it's still the top its next node is unchanged
+            if self
+                .top
+                .compare_exchange(top, next, Acquire, Relaxed)
+                .is_ok()
+            {
+                break top;
+            }
+        };
+
+        // SAFETY: We are the owner of the hazard pointer, and we are done reading the node
+        unsafe { hzrd_ptr.release() };
+
+        // SAFETY: The node is unlinked, and only we may move the value out
+        // Other poppers may still read the next pointer, but never the value
+        let val = unsafe { core::ptr::read(&(*top).val) };
+
+        /// Free a node, without dropping its value which has been moved out
+        unsafe fn free_node<T>(ptr: NonNull<()>) {
+            let node = ptr.cast::<Node<T>>().as_ptr();
+
+            // SAFETY: The node was allocated with `Box`, and nothing else holds it anymore
+            unsafe {
+                core::ptr::drop_in_place(core::ptr::addr_of_mut!((*node).next));
+                dealloc(node.cast(), Layout::new::<Node<T>>());
+            }
+        }
+
+        // SAFETY: The node was allocated with `Box`, and is freed once it's no longer protected
+        let node = unsafe { NonNull::new_unchecked(top) }.cast();
+        GlobalDomain.retire(unsafe { RetiredPtr::with_dropper(node, free_node::<T>) });
+
+        Some(val)
+    }
+
+    /// Take all the values of the stack, leaving it empty
+    ///
+    /// # Safety
+    /// The stack may not be popped from or iterated over at the same time
     pub unsafe fn take(&self) -> Self {
-        std::sync::atomic::fence(SeqCst);
-        let top = self.top.swap(std::ptr::null_mut(), Acquire);
+        // Acquire: Synchronizes with the push of the chain, making all the nodes visible to us
+        let top = self.top.swap(core::ptr::null_mut(), Acquire);
         Self {
             top: AtomicPtr::new(top),
         }
     }
 
+    /// Remove all the values matching the predicate, and return them as a new stack
+    ///
+    /// The nodes are relinked in place, and so no values are moved and nothing is allocated. Both
+    /// the remaining values and the removed ones keep their order. The chain is taken off the stack
+    /// while it's filtered, such that pushes can run concurrently, and the remaining values are
+    /// pushed back afterwards. Values pushed in the meantime will end up below them. The chain is
+    /// always taken before the predicate is first called.
+    ///
+    /// The swap gives us exclusive ownership of the nodes, and so concurrent pushes, takes and
+    /// filters are fine. Only a concurrent iteration (or pop) may still be looking at the nodes
+    /// while they are relinked.
+    ///
+    /// # Safety
+    /// The stack may not be popped from or iterated over at the same time
+    pub unsafe fn drain_filter(&self, mut pred: impl FnMut(&T) -> bool) -> Self {
+        /// Append a node to the chain given by `head` and `tail`
+        fn append<T>(head: &mut *mut Node<T>, tail: &mut *mut Node<T>, node: *mut Node<T>) {
+            match tail.is_null() {
+                true => *head = node,
+                // SAFETY: We own all the nodes of the chain
+                false => unsafe { &**tail }.next.store(node, Relaxed),
+            }
+            *tail = node;
+        }
+
+        // Acquire: Synchronizes with the push of the chain, making all the nodes visible to us
+        let mut current = self.top.swap(core::ptr::null_mut(), Acquire);
+
+        let (mut kept_head, mut kept_tail) = (core::ptr::null_mut(), core::ptr::null_mut());
+        let (mut removed_head, mut removed_tail) = (core::ptr::null_mut(), core::ptr::null_mut());
+        while !current.is_null() {
+            // SAFETY: We own all the nodes of the chain
+            let node = unsafe { &*current };
+            let next = node.next.load(Relaxed);
+            match pred(&node.val) {
+                true => append(&mut removed_head, &mut removed_tail, current),
+                false => append(&mut kept_head, &mut kept_tail, current),
+            }
+            current = next;
+        }
+
+        if !removed_tail.is_null() {
+            // SAFETY: We own all the nodes of the chain
+            unsafe { &*removed_tail }
+                .next
+                .store(core::ptr::null_mut(), Relaxed);
+        }
+
+        // The end of the kept chain is linked to the current top when it's pushed
+        if !kept_head.is_null() {
+            self.__push_chain(kept_head, kept_tail);
+        }
+
+        Self {
+            top: AtomicPtr::new(removed_head),
+        }
+    }
+
     /// Create an iterator over the stack
     pub fn iter(&self) -> Iter<'_, T> {
-        std::sync::atomic::fence(SeqCst);
+        // Acquire: Synchronizes with the push of the chain, making all the nodes visible to us
         Iter {
-            next: AtomicPtr::new(self.top.load(SeqCst)),
+            next: self.top.load(Acquire),
             _marker: PhantomData,
         }
     }
@@ -106,7 +278,7 @@ impl<T> SharedStack<T> {
 }
 
 impl<T: Debug> Debug for SharedStack<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
 }
@@ -139,8 +311,8 @@ impl<T> IntoIterator for SharedStack<T> {
     type Item = T;
     type IntoIter = IntoIter<T>;
     fn into_iter(self) -> Self::IntoIter {
-        let next = self.top.load(SeqCst);
-        std::mem::forget(self);
+        let next = self.top.load(Relaxed);
+        core::mem::forget(self);
         IntoIter { next }
     }
 }
@@ -155,9 +327,10 @@ impl<'t, T> IntoIterator for &'t SharedStack<T> {
 
 impl<T> Drop for SharedStack<T> {
     fn drop(&mut self) {
-        let mut current = self.top.load(SeqCst);
+        // We have exclusive access, and so all the nodes are already visible to us
+        let mut current = self.top.load(Relaxed);
         while !current.is_null() {
-            let next = unsafe { (*current).next.load(Acquire) };
+            let next = unsafe { (*current).next.load(Relaxed) };
             unsafe { drop(Box::from_raw(current)) };
             current = next;
         }
@@ -178,14 +351,14 @@ impl<T> Iterator for IntoIter<T> {
         }
 
         let current = unsafe { Box::from_raw(self.next) };
-        self.next = current.next.load(SeqCst);
+        self.next = current.next.load(Relaxed);
         Some(current.val)
     }
 }
 
 #[derive(Debug)]
 pub struct Iter<'t, T> {
-    next: AtomicPtr<Node<T>>,
+    next: *const Node<T>,
     _marker: PhantomData<&'t SharedStack<T>>,
 }
 
@@ -193,13 +366,15 @@ impl<'t, T> Iterator for Iter<'t, T> {
     type Item = &'t T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.next.load(Acquire);
-        if next.is_null() {
+        if self.next.is_null() {
             return None;
         }
-        let Node { val, next } = unsafe { &*next };
-        let new_next = next.load(Acquire);
-        self.next.store(new_next, Release);
+
+        // SAFETY: Nodes are only removed by `take` or `pop`, which may not be called while iterating
+        let Node { val, next } = unsafe { &*self.next };
+
+        // The node was made visible to us when the top was loaded
+        self.next = next.load(Relaxed);
         Some(val)
     }
 }
@@ -265,6 +440,118 @@ mod tests {
         });
     }
 
+    #[test]
+    fn push_stack() {
+        let stack = stack();
+        stack.push_stack(SharedStack::from_iter([3, 4, 5]));
+        stack.push_stack(SharedStack::new());
+        assert_eq!(stack.to_vec(), [5, 4, 3, 2, 1, 0]);
+
+        let empty = SharedStack::new();
+        empty.push_stack(stack);
+        assert_eq!(empty.to_vec(), [5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn drain_filter() {
+        let stack: SharedStack<i32> = (0..10).collect();
+        let odd = unsafe { stack.drain_filter(|val| val % 2 == 1) };
+        assert_eq!(stack.to_vec(), [8, 6, 4, 2, 0]);
+        assert_eq!(odd.to_vec(), [9, 7, 5, 3, 1]);
+
+        // Removing nothing, and removing everything, leaves the stack intact
+        assert!(unsafe { stack.drain_filter(|_| false) }.to_vec().is_empty());
+        assert_eq!(stack.to_vec(), [8, 6, 4, 2, 0]);
+        assert_eq!(
+            unsafe { stack.drain_filter(|_| true) }.to_vec(),
+            [8, 6, 4, 2, 0]
+        );
+        assert!(stack.to_vec().is_empty());
+    }
+
+    #[test]
+    fn concurrent_push_drain_filter() {
+        let stack = SharedStack::new();
+
+        std::thread::scope(|s| {
+            for i in 0..4 {
+                let stack = &stack;
+                s.spawn(move || (0..100).for_each(|j| stack.push(i * 100 + j)));
+            }
+
+            // Values pushed during the filtering are never lost
+            for _ in 0..10 {
+                drop(unsafe { stack.drain_filter(|val| val % 2 == 1) });
+            }
+        });
+
+        drop(unsafe { stack.drain_filter(|val| val % 2 == 1) });
+        let mut values = stack.to_vec();
+        values.sort_unstable();
+        assert_eq!(values, Vec::from_iter((0..400).step_by(2)));
+    }
+
+    #[test]
+    fn pop() {
+        let stack = stack();
+        assert_eq!(unsafe { stack.pop() }, Some(2));
+        stack.push(3);
+        assert_eq!(stack.to_vec(), [3, 1, 0]);
+
+        let popped: Vec<_> = std::iter::from_fn(|| unsafe { stack.pop() }).collect();
+        assert_eq!(popped, [3, 1, 0]);
+        assert_eq!(unsafe { stack.pop() }, None);
+    }
+
+    #[test]
+    fn concurrent_push_pop() {
+        let stack = SharedStack::new();
+
+        let popped: Vec<i32> = std::thread::scope(|s| {
+            for i in 0..4 {
+                let stack = &stack;
+                s.spawn(move || (0..100).for_each(|j| stack.push(i * 100 + j)));
+            }
+
+            // Several consumers pop while the producers are pushing
+            let consumers: Vec<_> = (0..4)
+                .map(|_| {
+                    let stack = &stack;
+                    s.spawn(move || {
+                        let mut popped = Vec::new();
+                        for _ in 0..1000 {
+                            popped.extend(unsafe { stack.pop() });
+                        }
+                        popped
+                    })
+                })
+                .collect();
+
+            consumers
+                .into_iter()
+                .flat_map(|consumer| consumer.join().unwrap())
+                .collect()
+        });
+
+        // Every value is either popped exactly once, or still in the stack
+        let mut values = popped;
+        values.extend(std::iter::from_fn(|| unsafe { stack.pop() }));
+        values.sort_unstable();
+        assert_eq!(values, Vec::from_iter(0..400));
+    }
+
+    #[test]
+    fn pop_drops_values_once() {
+        let stack = SharedStack::new();
+        stack.push(String::from("A"));
+        stack.push(String::from("B"));
+
+        // The popped value is moved out, while the node is retired
+        assert_eq!(unsafe { stack.pop() }.as_deref(), Some("B"));
+        GlobalDomain.reclaim();
+        assert_eq!(Vec::from_iter(stack.iter().cloned()), ["A"]);
+    }
+
     #[test]
     fn iterator() {
         let mut stack = SharedStack::from_iter([String::from("A"), String::from("B")]);
@@ -0,0 +1,83 @@
+/*!
+Building blocks for state structs made up of multiple cells
+
+A common pattern is a struct where every field is shared and mutable, such as the state of an application. This is best done with one [`HzrdCell`] per field, all sharing a single [`SharedDomain`] held in an [`Arc`]. Each field can then be updated independently, while all garbage is collected in the same domain.
+
+This module provides the types such structs are built from, and documents the shape they are expected to take. The intention is that code generation, such as an attribute macro, can target this pattern through stable APIs. All generated code should only rely on:
+- [`StateCell`], the type of each field
+- [`HzrdCell::new_in`], to construct each field in the shared domain
+- [`HzrdCell::read`] & [`HzrdCell::set`], for the accessors
+- [`HzrdCell::domain`], for getting hold of the shared domain
+
+# Example
+A state struct written as
+
+```ignore
+#[hzrd]
+pub struct AppState {
+    pub counter: u64,
+    pub name: String,
+}
+```
+
+is expected to expand to the following:
+
+```
+use std::sync::Arc;
+
+use hzrd::core::ReadHandle;
+use hzrd::domains::SharedDomain;
+use hzrd::state::StateCell;
+use hzrd::HzrdCell;
+
+pub struct AppState {
+    counter: StateCell<u64>,
+    name: StateCell<String>,
+}
+
+impl AppState {
+    pub fn new(counter: u64, name: String) -> Self {
+        let domain = Arc::new(SharedDomain::new());
+        Self {
+            counter: HzrdCell::new_in(counter, Arc::clone(&domain)),
+            name: HzrdCell::new_in(name, domain),
+        }
+    }
+
+    pub fn counter(&self) -> ReadHandle<'_, u64> {
+        self.counter.read()
+    }
+
+    pub fn set_counter(&self, value: u64) {
+        self.counter.set(value);
+    }
+
+    pub fn name(&self) -> ReadHandle<'_, String> {
+        self.name.read()
+    }
+
+    pub fn set_name(&self, value: String) {
+        self.name.set(value);
+    }
+
+    pub fn domain(&self) -> &Arc<SharedDomain> {
+        self.counter.domain()
+    }
+}
+
+let state = AppState::new(0, String::from("hzrd"));
+state.set_counter(*state.counter() + 1);
+assert_eq!(*state.counter(), 1);
+assert_eq!(*state.name(), "hzrd");
+```
+
+The domain is not stored separately, as every field already holds a reference to it.
+*/
+
+use alloc::sync::Arc;
+
+use crate::domains::SharedDomain;
+use crate::HzrdCell;
+
+/// A cell belonging to a [`SharedDomain`] which is shared with other cells
+pub type StateCell<T> = HzrdCell<T, Arc<SharedDomain>>;
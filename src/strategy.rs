@@ -0,0 +1,207 @@
+/*!
+Hot-swappable behavior, based on [`HzrdCell`]
+
+This module provides the [`StrategyCell`], which holds a closure that can be swapped out at runtime. Readers call the current closure while writers replace it, and the hazard pointers keep the old closure alive for any calls that are still in flight.
+
+```
+use hzrd::strategy::StrategyCell;
+
+let strategy = StrategyCell::new(|x: i32| x + 1);
+assert_eq!(strategy.call(1), 2);
+
+strategy.set(|x: i32| x * 10);
+assert_eq!(strategy.call(1), 10);
+```
+*/
+
+use alloc::boxed::Box;
+
+use crate::core::Domain;
+use crate::domains::GlobalDomain;
+use crate::HzrdCell;
+
+/// The boxed closure held by a [`StrategyCell`]
+pub type Strategy<Args, Ret> = Box<dyn Fn(Args) -> Ret + Send + Sync>;
+
+/**
+Holds a closure which can be swapped out while it's being called
+
+The closure is stored as a [`Box<dyn Fn>`](Strategy), which in turn is held by a [`HzrdCell`]. This means the cell only ever swaps thin pointers, while the closure itself can be of any type.
+
+For closures taking multiple arguments the arguments can be passed as a tuple.
+
+# Example
+```
+use hzrd::strategy::StrategyCell;
+
+let strategy = StrategyCell::new(|(a, b): (i32, i32)| a + b);
+assert_eq!(strategy.call((1, 2)), 3);
+```
+*/
+pub struct StrategyCell<Args, Ret, D = GlobalDomain> {
+    cell: HzrdCell<Strategy<Args, Ret>, D>,
+}
+
+impl<Args: 'static, Ret: 'static> StrategyCell<Args, Ret> {
+    /**
+    Construct a new [`StrategyCell`] with the given closure in the default domain
+
+    # Example
+    ```
+    # use hzrd::strategy::StrategyCell;
+    let strategy = StrategyCell::new(|x: u8| x.is_ascii_digit());
+    # assert!(strategy.call(b'0'));
+    ```
+    */
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(Args) -> Ret + Send + Sync + 'static,
+    {
+        Self::new_in(f, GlobalDomain)
+    }
+}
+
+impl<Args: 'static, Ret: 'static, D> StrategyCell<Args, Ret, D> {
+    /**
+    Construct a new [`StrategyCell`] with the given closure in the given domain
+
+    See [`HzrdCell::new_in`] for more details.
+
+    # Example
+    ```
+    # use hzrd::domains::SharedDomain;
+    # use hzrd::strategy::StrategyCell;
+    let strategy = StrategyCell::new_in(|x: i32| -x, SharedDomain::new());
+    # assert_eq!(strategy.call(1), -1);
+    ```
+    */
+    pub fn new_in<F>(f: F, domain: D) -> Self
+    where
+        F: Fn(Args) -> Ret + Send + Sync + 'static,
+    {
+        Self {
+            cell: HzrdCell::new_in(Box::new(f), domain),
+        }
+    }
+}
+
+impl<Args: 'static, Ret: 'static, D: Domain> StrategyCell<Args, Ret, D> {
+    /**
+    Call the current closure with the given arguments
+
+    The closure is protected by a hazard pointer for the duration of the call, so a concurrent call to [`set`](StrategyCell::set) will not free the closure before the call has returned.
+
+    # Example
+    ```
+    # use hzrd::strategy::StrategyCell;
+    let strategy = StrategyCell::new(|s: &'static str| s.len());
+    assert_eq!(strategy.call("Hello"), 5);
+    ```
+    */
+    pub fn call(&self, args: Args) -> Ret {
+        let strategy = self.cell.read();
+        strategy(args)
+    }
+
+    /**
+    Swap in a new closure
+
+    The old closure is retired, and will be freed once no calls to it are in flight.
+
+    # Example
+    ```
+    # use hzrd::strategy::StrategyCell;
+    let strategy = StrategyCell::new(|x: i32| x);
+    strategy.set(|x: i32| 2 * x);
+    assert_eq!(strategy.call(2), 4);
+    ```
+    */
+    pub fn set<F>(&self, f: F)
+    where
+        F: Fn(Args) -> Ret + Send + Sync + 'static,
+    {
+        self.cell.set(Box::new(f));
+    }
+
+    /**
+    Reclaim available memory, if possible
+
+    See [`HzrdCell::reclaim`] for more details.
+    */
+    pub fn reclaim(&self) {
+        self.cell.reclaim();
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering::*};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domains::{LocalDomain, SharedDomain};
+
+    /// Increments the counter when dropped
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+
+    #[test]
+    fn local_domain() {
+        let strategy = StrategyCell::new_in(|x: usize| x + 1, LocalDomain::new());
+        assert_eq!(strategy.call(0), 1);
+
+        let offset = String::from("Hello");
+        strategy.set(move |x: usize| x + offset.len());
+        assert_eq!(strategy.call(0), 5);
+    }
+
+    #[test]
+    fn swap_mid_flight() {
+        let strategy = StrategyCell::new_in(|_: ()| 0, SharedDomain::new());
+
+        let entered = Arc::new(AtomicBool::new(false));
+        let swapped = Arc::new(AtomicBool::new(false));
+        let drops = Arc::new(AtomicUsize::new(0));
+
+        let counter = DropCounter(Arc::clone(&drops));
+        let (entered_clone, swapped_clone) = (Arc::clone(&entered), Arc::clone(&swapped));
+        strategy.set(move |_: ()| {
+            let _counter = &counter;
+            entered_clone.store(true, SeqCst);
+            while !swapped_clone.load(SeqCst) {
+                std::thread::yield_now();
+            }
+            1
+        });
+
+        std::thread::scope(|s| {
+            let handle = s.spawn(|| strategy.call(()));
+
+            while !entered.load(SeqCst) {
+                std::thread::yield_now();
+            }
+
+            // Swap out the closure while it's being called
+            strategy.set(|_: ()| 2);
+            strategy.reclaim();
+            let drops_in_flight = drops.load(SeqCst);
+            swapped.store(true, SeqCst);
+
+            assert_eq!(drops_in_flight, 0);
+
+            assert_eq!(handle.join().unwrap(), 1);
+        });
+
+        assert_eq!(strategy.call(()), 2);
+
+        strategy.reclaim();
+        assert_eq!(drops.load(SeqCst), 1);
+    }
+}
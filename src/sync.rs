@@ -0,0 +1,36 @@
+/*!
+Atomics used for synchronization within the crate
+
+When compiled with `--cfg loom` the atomics from [loom](https://docs.rs/loom) are used instead of the ones from the standard library, such that the concurrent code can be model checked. See `tests/loom.rs` for how to run these tests.
+*/
+
+#[cfg(loom)]
+pub(crate) use loom::sync::atomic::{fence, AtomicPtr, AtomicUsize};
+
+#[cfg(not(loom))]
+pub(crate) use core::sync::atomic::{fence, AtomicPtr, AtomicUsize};
+
+#[cfg(all(loom, feature = "async"))]
+pub(crate) use loom::sync::atomic::AtomicBool;
+
+#[cfg(all(not(loom), feature = "async"))]
+pub(crate) use core::sync::atomic::AtomicBool;
+
+#[cfg(all(loom, debug_assertions))]
+pub(crate) use loom::sync::atomic::AtomicU64;
+
+#[cfg(all(not(loom), debug_assertions))]
+pub(crate) use core::sync::atomic::AtomicU64;
+
+/// Declare a `const fn`, which is not `const` under loom (loom atomics can not be constructed in constant contexts)
+macro_rules! const_fn {
+    ($(#[$attr:meta])* $vis:vis fn $($rest:tt)*) => {
+        #[cfg(not(loom))]
+        $(#[$attr])*
+        $vis const fn $($rest)*
+
+        #[cfg(loom)]
+        $(#[$attr])*
+        $vis fn $($rest)*
+    };
+}
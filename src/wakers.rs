@@ -0,0 +1,99 @@
+use std::sync::atomic::Ordering::*;
+use std::sync::Mutex;
+use std::task::Waker;
+
+use crate::sync::AtomicBool;
+
+/// A set of wakers waiting for the value of a cell to change
+#[derive(Default)]
+pub(crate) struct WakerRegistry {
+    wakers: Mutex<Vec<Waker>>,
+    /// Set whenever there may be registered wakers, such that writes can skip the lock when there are none
+    has_wakers: AtomicBool,
+}
+
+impl WakerRegistry {
+    /**
+    Register a waker to be woken on the next change
+
+    The caller must check the value again after registering, as a write that happened before the registration will not wake the waker. This pairs with the check in [`wake_all`](WakerRegistry::wake_all): Either the write sees the registration, or the second check sees the write.
+    */
+    pub(crate) fn register(&self, waker: &Waker) {
+        let mut wakers = self.wakers.lock().unwrap_or_else(|e| e.into_inner());
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+
+        // This must be sequentially consistent with the write and the check that follows it
+        self.has_wakers.store(true, SeqCst);
+    }
+
+    /// Wake all registered wakers, and clear the registry
+    pub(crate) fn wake_all(&self) {
+        // Writes with no waiting tasks don't need to take the lock (must come after the write to the value)
+        if !self.has_wakers.load(SeqCst) {
+            return;
+        }
+
+        let wakers = {
+            let mut wakers = self.wakers.lock().unwrap_or_else(|e| e.into_inner());
+            self.has_wakers.store(false, SeqCst);
+            std::mem::take(&mut *wakers)
+        };
+
+        // The wakers are woken after the lock is released, in case they try to register again
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+// ------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering::*};
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+
+    use super::WakerRegistry;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+
+    #[test]
+    fn wake_once() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(Arc::clone(&counter));
+
+        let registry = WakerRegistry::default();
+        registry.register(&waker);
+        registry.register(&waker);
+
+        registry.wake_all();
+        registry.wake_all();
+        assert_eq!(counter.0.load(SeqCst), 1);
+    }
+
+    #[test]
+    fn has_wakers() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(Arc::clone(&counter));
+
+        let registry = WakerRegistry::default();
+        assert!(!registry.has_wakers.load(SeqCst));
+
+        registry.register(&waker);
+        assert!(registry.has_wakers.load(SeqCst));
+
+        // The flag is cleared with the registry, and set again on the next registration
+        registry.wake_all();
+        assert!(!registry.has_wakers.load(SeqCst));
+        registry.register(&waker);
+        registry.wake_all();
+        assert_eq!(counter.0.load(SeqCst), 2);
+    }
+}
@@ -0,0 +1,56 @@
+//! Tests depending on the global config, which can only be set once per process
+#![cfg(feature = "std")]
+
+use hzrd::core::Domain;
+use hzrd::domains::{Config, LocalDomain, SharedDomain, GLOBAL_CONFIG};
+use hzrd::HzrdCell;
+
+fn bulk_size_4() {
+    let _ = GLOBAL_CONFIG.set(Config::default().bulk_size(4));
+}
+
+fn reclaim_all(domain: impl Domain) {
+    bulk_size_4();
+    let cell = HzrdCell::new_in(0, &domain);
+
+    cell.just_set(1);
+    cell.just_set(2);
+
+    // Below the bulk size nothing should be reclaimed, and nothing should be lost
+    assert_eq!(domain.reclaim(), 0);
+    assert_eq!(domain.retired_count(), 2);
+
+    assert_eq!(domain.reclaim_all(), 2);
+    assert_eq!(domain.retired_count(), 0);
+    assert_eq!(cell.get(), 2);
+}
+
+#[test]
+fn shared_domain_reclaim_all() {
+    reclaim_all(SharedDomain::new());
+}
+
+#[test]
+fn local_domain_reclaim_all() {
+    reclaim_all(LocalDomain::new());
+}
+
+fn domain_config_overrides_global(domain: impl Domain) {
+    bulk_size_4();
+    let cell = HzrdCell::new_in(0, &domain);
+
+    // The domain has a bulk size of 1, so garbage is reclaimed straight away
+    cell.just_set(1);
+    assert_eq!(domain.reclaim(), 1);
+    assert_eq!(domain.retired_count(), 0);
+}
+
+#[test]
+fn shared_domain_config() {
+    domain_config_overrides_global(SharedDomain::with_config(Config::default()));
+}
+
+#[test]
+fn local_domain_config() {
+    domain_config_overrides_global(LocalDomain::with_config(Config::default()));
+}
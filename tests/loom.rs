@@ -0,0 +1,127 @@
+//! Model checked tests, run with `RUSTFLAGS="--cfg loom" cargo test --release --test loom`
+
+#![cfg(loom)]
+
+use loom::sync::atomic::{AtomicBool, AtomicUsize, Ordering::*};
+use loom::sync::Arc;
+use loom::thread;
+
+use hzrd::core::Domain;
+use hzrd::domains::{GenerationDomain, SharedDomain};
+use hzrd::HzrdCell;
+
+/// Sets the flag when dropped
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, SeqCst);
+    }
+}
+
+/// Increments the counter when dropped
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, SeqCst);
+    }
+}
+
+fn model(f: impl Fn() + Sync + Send + 'static) {
+    let mut builder = loom::model::Builder::new();
+    builder.preemption_bound.get_or_insert(3);
+    builder.check(f);
+}
+
+#[test]
+fn reclaim_while_reading() {
+    model(|| {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let value = DropFlag(Arc::clone(&dropped));
+        let cell = Arc::new(HzrdCell::new_in(value, SharedDomain::new()));
+
+        let reader = thread::spawn({
+            let cell = Arc::clone(&cell);
+            let dropped = Arc::clone(&dropped);
+            move || {
+                let handle = cell.read();
+
+                // If the handle holds the old value it must not have been freed
+                if Arc::ptr_eq(&handle.0, &dropped) {
+                    assert!(!dropped.load(SeqCst));
+                }
+
+                drop(handle);
+            }
+        });
+
+        cell.just_set(DropFlag(Arc::new(AtomicBool::new(false))));
+        cell.domain().reclaim_all();
+
+        reader.join().unwrap();
+
+        // Once the reader is done nothing is protecting the old value
+        cell.domain().reclaim_all();
+        assert!(dropped.load(SeqCst));
+        assert_eq!(cell.domain().retired_count(), 0);
+    });
+}
+
+#[test]
+fn concurrent_reclaim() {
+    model(|| {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let value = DropCounter(Arc::clone(&drops));
+        let cell = Arc::new(HzrdCell::new_in(value, SharedDomain::new()));
+
+        // Both writers retire and reclaim, racing on the stack of retired values
+        let writers: Vec<_> = (0..2)
+            .map(|_| {
+                let cell = Arc::clone(&cell);
+                let value = DropCounter(Arc::clone(&drops));
+                thread::spawn(move || cell.set(value))
+            })
+            .collect();
+
+        drop(cell.read());
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        // Every retired value should be reclaimed exactly once
+        cell.domain().reclaim_all();
+        assert_eq!(drops.load(SeqCst), 2);
+        assert_eq!(cell.domain().retired_count(), 0);
+    });
+}
+
+#[test]
+fn generation_reclaim_while_reading() {
+    model(|| {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let value = DropFlag(Arc::clone(&dropped));
+        let domain = GenerationDomain::with_generation_size(1);
+        let cell = Arc::new(HzrdCell::new_in(value, domain));
+
+        // The reader may announce its generation before or after the old value is retired
+        let reader = thread::spawn({
+            let cell = Arc::clone(&cell);
+            let dropped = Arc::clone(&dropped);
+            move || {
+                let handle = cell.read();
+                if Arc::ptr_eq(&handle.0, &dropped) {
+                    assert!(!dropped.load(SeqCst));
+                }
+            }
+        });
+
+        cell.set(DropFlag(Arc::new(AtomicBool::new(false))));
+        reader.join().unwrap();
+
+        cell.domain().reclaim_all();
+        assert!(dropped.load(SeqCst));
+        assert_eq!(cell.domain().retired_count(), 0);
+    });
+}
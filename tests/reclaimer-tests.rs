@@ -0,0 +1,38 @@
+//! Tests of the background reclaimer, which reclaims the global domain (and so needs a process of its own)
+#![cfg(feature = "std")]
+
+use std::sync::atomic::{AtomicBool, Ordering::*};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use hzrd::HzrdCell;
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, SeqCst);
+    }
+}
+
+#[test]
+fn background_reclaim() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let cell = HzrdCell::new(Some(DropFlag(Arc::clone(&dropped))));
+
+    // The value can not be reclaimed while it's protected
+    let handle = cell.read();
+    cell.set(None);
+    drop(handle);
+    assert!(!dropped.load(SeqCst));
+
+    let reclaimer = hzrd::spawn_global_reclaimer(Duration::from_millis(10));
+
+    let start = Instant::now();
+    while !dropped.load(SeqCst) {
+        assert!(start.elapsed() < Duration::from_secs(10));
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    reclaimer.stop();
+}
@@ -90,8 +90,53 @@ fn holding_handles(domain: impl Domain + Send + Sync) {
     });
 }
 
+// The compare-exchange backs off by yielding to the writers, which needs `std`. Without it the
+// backoff only spins, and a single CPU never runs the writers in between.
+#[cfg(feature = "std")]
+fn contended_compare_exchange(domain: impl Domain + Send + Sync) {
+    use std::sync::atomic::AtomicBool;
+    use std::time::Instant;
+
+    use hzrd::CasResult;
+
+    let cell = HzrdCell::new_in(0, domain);
+    let done = AtomicBool::new(false);
+
+    // The handle will keep the old value alive, so the exchange will never succeed
+    let outdated = cell.read();
+
+    std::thread::scope(|s| {
+        for i in 1..=4 {
+            let (cell, done) = (&cell, &done);
+            s.spawn(move || {
+                while !done.load(SeqCst) {
+                    cell.set(i);
+                }
+            });
+        }
+
+        // Wait for the writers to get going
+        while cell.get() == 0 {
+            std::hint::spin_loop();
+        }
+
+        let start = Instant::now();
+        let mut contended = false;
+        while !contended && start.elapsed() < Duration::from_secs(5) {
+            match cell.try_compare_exchange(&outdated, -1) {
+                CasResult::Succeeded => panic!("value should have changed"),
+                CasResult::Mismatch(current, new) => assert!(*current > 0 && new == -1),
+                CasResult::Contended(new) => contended = new == -1,
+            }
+        }
+
+        done.store(true, SeqCst);
+        assert!(contended, "heavy churn should be reported as contention");
+    });
+}
+
 mod global_domain {
-    use hzrd::domains::GlobalDomain;
+    use hzrd::GlobalDomain;
 
     #[test]
     fn read_unchecked() {
@@ -112,10 +157,16 @@ mod global_domain {
     fn holding_handles() {
         super::holding_handles(GlobalDomain);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn contended_compare_exchange() {
+        super::contended_compare_exchange(GlobalDomain);
+    }
 }
 
 mod shared_domain {
-    use hzrd::domains::SharedDomain;
+    use hzrd::SharedDomain;
 
     #[test]
     fn read_unchecked() {
@@ -136,4 +187,10 @@ mod shared_domain {
     fn holding_handles() {
         super::holding_handles(SharedDomain::new());
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn contended_compare_exchange() {
+        super::contended_compare_exchange(SharedDomain::new());
+    }
 }
@@ -0,0 +1,13 @@
+use hzrd::domains::SharedDomain;
+use hzrd::HzrdCell;
+
+// The cell can not be returned, as it borrows a domain local to the function
+fn make_cell() -> HzrdCell<i32, &'static SharedDomain> {
+    let domain = SharedDomain::new();
+    HzrdCell::new_in(0, &domain)
+}
+
+fn main() {
+    let cell = make_cell();
+    assert_eq!(cell.get(), 0);
+}
@@ -0,0 +1,10 @@
+use hzrd::domains::LocalDomain;
+use hzrd::HzrdCell;
+
+fn main() {
+    // The cell borrows the domain, so the domain can not be dropped while the cell is alive
+    let domain = LocalDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    drop(domain);
+    cell.set(1);
+}
@@ -0,0 +1,12 @@
+use hzrd::domains::SharedDomain;
+use hzrd::HzrdCell;
+
+fn main() {
+    // The domain goes out of scope before the cell
+    let cell;
+    {
+        let domain = SharedDomain::new();
+        cell = HzrdCell::new_in(0, &domain);
+    }
+    assert_eq!(cell.get(), 0);
+}
@@ -0,0 +1,10 @@
+use hzrd::HzrdCell;
+
+fn main() {
+    // A read handle borrows the cell, so the value can not be mutated while it's held
+    let mut cell = HzrdCell::new(vec![1, 2, 3]);
+
+    let handle = cell.read();
+    cell.get_mut().push(4);
+    assert_eq!(handle.len(), 3);
+}
@@ -0,0 +1,12 @@
+use std::cell::Cell;
+
+use hzrd::HzrdCell;
+
+fn main() {
+    // `Cell` is not `Sync`, so the cell can not be shared across threads
+    let cell: HzrdCell<Cell<i32>> = HzrdCell::new(Cell::new(0));
+
+    std::thread::scope(|s| {
+        s.spawn(|| cell.read().set(1));
+    });
+}
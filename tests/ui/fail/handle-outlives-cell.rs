@@ -0,0 +1,11 @@
+use hzrd::domains::SharedDomain;
+use hzrd::HzrdCell;
+
+fn main() {
+    // The handle borrows the cell, and with it the domain holding its hazard pointer
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(String::from("Hello"), &domain);
+    let handle = cell.read();
+    drop(cell);
+    assert_eq!(*handle, "Hello");
+}
@@ -0,0 +1,19 @@
+use hzrd::domains::{LocalDomain, SharedDomain};
+use hzrd::HzrdCell;
+
+fn main() {
+    // Cells can borrow a domain, as long as they are dropped before it
+    let domain = LocalDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    cell.set(1);
+    drop(cell);
+    drop(domain);
+
+    // Scoped threads can share a cell borrowing a domain
+    let domain = SharedDomain::new();
+    let cell = HzrdCell::new_in(0, &domain);
+    std::thread::scope(|s| {
+        s.spawn(|| cell.set(1));
+    });
+    assert_eq!(cell.get(), 1);
+}
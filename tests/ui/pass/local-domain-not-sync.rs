@@ -0,0 +1,15 @@
+use std::cell::Cell;
+
+use hzrd::domains::LocalDomain;
+use hzrd::HzrdCell;
+
+fn main() {
+    // `Cell` is not `Sync`, but the cell never leaves this thread
+    let cell: HzrdCell<Cell<i32>, LocalDomain> = HzrdCell::new_in(Cell::new(0), LocalDomain::new());
+
+    cell.read().set(1);
+    assert_eq!(cell.read().get(), 1);
+
+    cell.set(Cell::new(2));
+    assert_eq!(cell.read().get(), 2);
+}